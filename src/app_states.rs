@@ -6,6 +6,9 @@ pub enum AppState {
     MainMenu,
     AudioMenu,
     CreditsMenu,
+    LoadMenu,
+    ControlsMenu,
+    BacklogMenu,
     Cutscene,
     InGame
 }
\ No newline at end of file
@@ -1,18 +1,32 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-pub const MAX_VOLUME: f32 = 2.0; 
+pub const MAX_VOLUME: f32 = 2.0;
 
-#[derive(Resource)]
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AudioSettings {
     pub bgm_volume: f32,
     pub sfx_volume: f32
 }
 
+impl AudioSettings {
+    // Volumes coming from the settings file may be out of range if it was edited by hand.
+    pub fn sanitized(self) -> Self {
+        let default = Self::default();
+
+        Self {
+            bgm_volume: if self.bgm_volume.is_finite() { self.bgm_volume.clamp(0.0, MAX_VOLUME) } else { default.bgm_volume },
+            sfx_volume: if self.sfx_volume.is_finite() { self.sfx_volume.clamp(0.0, MAX_VOLUME) } else { default.sfx_volume }
+        }
+    }
+}
+
 impl Default for AudioSettings {
     fn default() -> Self {
-        Self { 
+        Self {
             bgm_volume: 0.5,
             sfx_volume: 0.5
         }
     }
-}
\ No newline at end of file
+}
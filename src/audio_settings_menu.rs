@@ -1,6 +1,8 @@
-use bevy::{audio::Volume, color::Color, prelude::*};
+use bevy::{audio::Volume, color::Color, ecs::system::SystemParam, prelude::*};
 
-use crate::{app_states::AppState, audio_settings::{AudioSettings, MAX_VOLUME}, fade_in_fade_out::FadeInFadeOutNode, main_menu::{DEFAULT_FONT, NORMAL_BUTTON}, Cweampuff};
+use crate::{app_states::AppState, audio_settings::{AudioSettings, MAX_VOLUME}, fade_in_fade_out::FadeInFadeOutNode, main_menu::{DEFAULT_FONT, NORMAL_BUTTON}, settings::SettingsChangedEvent, controls::{ActionInput, GameAction}, Cweampuff};
+use crate::dialogue_settings::DialogueSettings;
+use crate::localization::{LocalizationSettings, LocalizedText};
 
 const VOLUME_STEP: f32 = 0.1;
 
@@ -10,6 +12,11 @@ pub enum SoundMenuButtonAction {
     DecreaseMusicVolume,
     IncreaseSoundVolume,
     DecreaseSoundVolume,
+    ChangeLanguage,
+    ChangeTextSpeed,
+    ToggleVoiceBlips,
+    Controls,
+    Backlog,
     Back
 }
 
@@ -22,13 +29,22 @@ pub struct SoundVolumeText;
 #[derive(Component)]
 pub struct MusicVolumeText;
 
+#[derive(Component)]
+pub struct LanguageText;
+
+#[derive(Component)]
+pub struct TextSpeedText;
+
+#[derive(Component)]
+pub struct VoiceBlipsText;
+
 pub fn settings_menu_input_reader(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    action_input: ActionInput,
     mut app_state: ResMut<NextState<AppState>>,
     current_app_state: Res<State<AppState>>,
     cweampuff_query: Query<&Cweampuff>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+    if action_input.just_pressed(GameAction::Pause) {
         if let AppState::AudioMenu = **current_app_state {
             if cweampuff_query.is_empty() {
                 app_state.set(AppState::MainMenu);
@@ -45,15 +61,74 @@ pub fn settings_menu_input_reader(
     }
 }
 
+type SoundMenuButtonInteraction<'a> = (&'a Interaction, &'a SoundMenuButtonAction);
+
+type LanguageTextFilter = (With<LanguageText>, Without<SoundVolumeText>, Without<MusicVolumeText>);
+
+// The texts showing the current settings, plus the music that has to follow the volume right away.
+#[derive(SystemParam)]
+pub struct SettingsMenuDisplay<'w, 's> {
+    sound_text_query: Query<'w, 's, &'static mut Text, (With<SoundVolumeText>, Without<MusicVolumeText>)>,
+    music_text_query: Query<'w, 's, &'static mut Text, (With<MusicVolumeText>, Without<SoundVolumeText>)>,
+    language_text_query: Query<'w, 's, &'static mut Text, LanguageTextFilter>,
+    text_speed_text_query: Query<'w, 's, &'static mut LocalizedText, (With<TextSpeedText>, Without<VoiceBlipsText>)>,
+    voice_blips_text_query: Query<'w, 's, &'static mut LocalizedText, (With<VoiceBlipsText>, Without<TextSpeedText>)>,
+    audio_query: Query<'w, 's, &'static mut AudioSink>,
+}
+
+impl SettingsMenuDisplay<'_, '_> {
+    fn show_language(&mut self, localization_settings: &LocalizationSettings) {
+        for mut language_text in self.language_text_query.iter_mut() {
+            **language_text = localization_settings.language.to_string();
+        }
+    }
+
+    fn show_dialogue_settings(&mut self, dialogue_settings: &DialogueSettings) {
+        for mut text_speed_text in self.text_speed_text_query.iter_mut() {
+            *text_speed_text = LocalizedText(dialogue_settings.text_speed.get_localization_id());
+        }
+
+        for mut voice_blips_text in self.voice_blips_text_query.iter_mut() {
+            *voice_blips_text = LocalizedText(get_toggle_localization_id(dialogue_settings.voice_blips));
+        }
+    }
+
+    fn show_volumes(&mut self, audio_settings: &AudioSettings) {
+        let sound_text_value = ((audio_settings.sfx_volume * 100.) / MAX_VOLUME).round() as i32;
+        let music_text_value = ((audio_settings.bgm_volume * 100.) / MAX_VOLUME).round() as i32;
+
+        for mut sound_text in self.sound_text_query.iter_mut() {
+            **sound_text = format!("{}", sound_text_value);
+        }
+
+        for mut music_text in self.music_text_query.iter_mut() {
+            **music_text = format!("{}", music_text_value);
+        }
+
+        for mut settings in self.audio_query.iter_mut() {        
+            settings.set_volume(Volume::Linear(audio_settings.bgm_volume));
+        }
+    }
+}
+
+// Everything the settings menu can change, along with the event that gets them written to the settings file.
+#[derive(SystemParam)]
+pub struct EditedSettings<'w> {
+    audio_settings: ResMut<'w, AudioSettings>,
+    localization_settings: ResMut<'w, LocalizationSettings>,
+    dialogue_settings: ResMut<'w, DialogueSettings>,
+    settings_writer: EventWriter<'w, SettingsChangedEvent>,
+}
+
 pub fn audio_button_interactions_handler(
-    mut interaction_query: Query<(&Interaction, &SoundMenuButtonAction), (Changed<Interaction>, With<Button>)>,
-    mut sound_text_query: Query<&mut Text, (With<SoundVolumeText>, Without<MusicVolumeText>)>,
-    mut music_text_query: Query<&mut Text, (With<MusicVolumeText>, Without<SoundVolumeText>)>,
-    mut audio_query: Query<&mut AudioSink>,
-    mut audio_settings: ResMut<AudioSettings>,
+    mut interaction_query: Query<SoundMenuButtonInteraction, (Changed<Interaction>, With<Button>)>,
+    mut settings_menu_display: SettingsMenuDisplay,
+    mut edited_settings: EditedSettings,
     mut app_state: ResMut<NextState<AppState>>,
     cweampuff_query: Query<&Cweampuff>,
 ) {
+    let EditedSettings { audio_settings, localization_settings, dialogue_settings, settings_writer } = &mut edited_settings;
+
     for (interaction, action) in &mut interaction_query {
         if let Interaction::Pressed = *interaction  {
             match action {
@@ -70,6 +145,28 @@ pub fn audio_button_interactions_handler(
                 SoundMenuButtonAction::DecreaseSoundVolume => {
                     audio_settings.sfx_volume = (audio_settings.sfx_volume - VOLUME_STEP).max(0.0);
                 },
+                SoundMenuButtonAction::ChangeLanguage => {
+                    localization_settings.language = localization_settings.language.get_next();
+                    settings_menu_display.show_language(localization_settings);
+                },
+                SoundMenuButtonAction::ChangeTextSpeed => {
+                    dialogue_settings.text_speed = dialogue_settings.text_speed.get_next();
+                    settings_menu_display.show_dialogue_settings(dialogue_settings);
+                },
+                SoundMenuButtonAction::ToggleVoiceBlips => {
+                    dialogue_settings.voice_blips = !dialogue_settings.voice_blips;
+                    settings_menu_display.show_dialogue_settings(dialogue_settings);
+                },
+                SoundMenuButtonAction::Controls => {
+                    app_state.set(AppState::ControlsMenu);
+
+                    return;
+                },
+                SoundMenuButtonAction::Backlog => {
+                    app_state.set(AppState::BacklogMenu);
+
+                    return;
+                },
                 SoundMenuButtonAction::Back => {
                     if !cweampuff_query.is_empty() {
                         app_state.set(AppState::InGame);
@@ -77,31 +174,32 @@ pub fn audio_button_interactions_handler(
                     else {
                         app_state.set(AppState::MainMenu);
                     }
+
+                    return;
                 },
             };
 
-            let sound_text_value = ((audio_settings.sfx_volume * 100.) / MAX_VOLUME).round() as i32;
-            let music_text_value = ((audio_settings.bgm_volume * 100.) / MAX_VOLUME).round() as i32;
-
-            for mut sound_text in sound_text_query.iter_mut() {
-                **sound_text = format!("{}", sound_text_value);
-            }
-
-            for mut music_text in music_text_query.iter_mut() {
-                **music_text = format!("{}", music_text_value);
-            }
-
-            for mut settings in audio_query.iter_mut() {        
-                settings.set_volume(Volume::Linear(audio_settings.bgm_volume));
-            }
+            settings_writer.write(SettingsChangedEvent);
+            settings_menu_display.show_volumes(audio_settings);
         }
     }
 }
 
+fn get_toggle_localization_id(is_on: bool) -> &'static str {
+    if is_on {
+        "settings_menu.on"
+    }
+    else {
+        "settings_menu.off"
+    }
+}
+
 pub fn spawn_audio_menu(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     audio_settings: Res<AudioSettings>,
+    localization_settings: Res<LocalizationSettings>,
+    dialogue_settings: Res<DialogueSettings>,
     cweampuff_query: Query<&Cweampuff>,
 ) {
     let sound_text_value = ((audio_settings.sfx_volume * 100.) / MAX_VOLUME).round() as i32;
@@ -117,6 +215,14 @@ pub fn spawn_audio_menu(
         0.95
     };
 
+    // The backlog only has something to show once a game is going.
+    let controls_button_left = if cweampuff_query.is_empty() {
+        45.
+    }
+    else {
+        37.5
+    };
+
     commands.spawn((
         Node {
             width: Val::Percent(100.0),
@@ -140,7 +246,7 @@ pub fn spawn_audio_menu(
         .with_children(|parent| {
             parent
                 .spawn((
-                    Text::new("Audio"),
+                    LocalizedText("settings_menu.title"),
                     TextShadow {
                         offset: Vec2::splat(2.),
                         color: Color::linear_rgba(0., 0., 0., 1.),
@@ -158,7 +264,7 @@ pub fn spawn_audio_menu(
         .spawn((Node {
             width: Val::Percent(60.0),
             height: Val::Percent(60.0),
-            top: Val::Percent(30.),
+            top: Val::Percent(25.),
             left: Val::Percent(15.),
             position_type: PositionType::Absolute,
             ..default()
@@ -169,7 +275,7 @@ pub fn spawn_audio_menu(
                     Node {
                         width: Val::Percent(50.0),
                         height: Val::Percent(20.0),
-                        top: Val::Percent(8.),
+                        top: Val::Percent(0.),
                         left: Val::Percent(10.),
                         position_type: PositionType::Absolute,
                         // horizontally center child text
@@ -180,7 +286,7 @@ pub fn spawn_audio_menu(
                     },
                 ))
                 .with_child((
-                    Text::new("Music Volume:"),
+                    LocalizedText("settings_menu.music_volume"),
                     TextShadow {
                         offset: Vec2::splat(2.),
                         color: Color::linear_rgba(0., 0., 0., 1.),
@@ -198,7 +304,7 @@ pub fn spawn_audio_menu(
                     Node {
                         width: Val::Percent(20.0),
                         height: Val::Percent(20.0),
-                        top: Val::Percent(8.),
+                        top: Val::Percent(0.),
                         left: Val::Percent(75.),
                         position_type: PositionType::Absolute,
                         // horizontally center child text
@@ -230,7 +336,7 @@ pub fn spawn_audio_menu(
                     Node {
                         width: Val::Percent(5.0),
                         height: Val::Percent(5.0),
-                        top: Val::Percent(13.),
+                        top: Val::Percent(5.),
                         left: Val::Percent(90.),
                         position_type: PositionType::Absolute,
                         border: UiRect::all(Val::Px(5.0)),
@@ -261,7 +367,7 @@ pub fn spawn_audio_menu(
                     Node {
                         width: Val::Percent(5.0),
                         height: Val::Percent(5.0),
-                        top: Val::Percent(19.),
+                        top: Val::Percent(11.),
                         left: Val::Percent(90.),
                         position_type: PositionType::Absolute,
                         border: UiRect::all(Val::Px(5.0)),
@@ -290,7 +396,7 @@ pub fn spawn_audio_menu(
                     Node {
                         width: Val::Percent(50.0),
                         height: Val::Percent(20.0),
-                        top: Val::Percent(38.),
+                        top: Val::Percent(20.),
                         left: Val::Percent(10.),
                         position_type: PositionType::Absolute,
                         // horizontally center child text
@@ -301,7 +407,7 @@ pub fn spawn_audio_menu(
                     },
                 ))
                 .with_child((
-                    Text::new("Sound Volume:"),
+                    LocalizedText("settings_menu.sound_volume"),
                     TextShadow {
                         offset: Vec2::splat(2.),
                         color: Color::linear_rgba(0., 0., 0., 1.),
@@ -319,7 +425,7 @@ pub fn spawn_audio_menu(
                     Node {
                         width: Val::Percent(20.0),
                         height: Val::Percent(20.0),
-                        top: Val::Percent(38.),
+                        top: Val::Percent(20.),
                         left: Val::Percent(75.),
                         position_type: PositionType::Absolute,
                         // horizontally center child text
@@ -351,7 +457,7 @@ pub fn spawn_audio_menu(
                     Node {
                         width: Val::Percent(5.0),
                         height: Val::Percent(5.0),
-                        top: Val::Percent(43.),
+                        top: Val::Percent(25.),
                         left: Val::Percent(90.),
                         position_type: PositionType::Absolute,
                         border: UiRect::all(Val::Px(5.0)),
@@ -382,7 +488,7 @@ pub fn spawn_audio_menu(
                     Node {
                         width: Val::Percent(5.0),
                         height: Val::Percent(5.0),
-                        top: Val::Percent(49.),
+                        top: Val::Percent(31.),
                         left: Val::Percent(90.),
                         position_type: PositionType::Absolute,
                         border: UiRect::all(Val::Px(5.0)),
@@ -405,8 +511,257 @@ pub fn spawn_audio_menu(
                     },
                     TextColor(Color::srgb(0.9, 0.9, 0.9)),
                 ));
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(50.0),
+                        height: Val::Percent(20.0),
+                        top: Val::Percent(40.),
+                        left: Val::Percent(10.),
+                        position_type: PositionType::Absolute,
+                        // horizontally center child text
+                        justify_content: JustifyContent::Center,
+                        // vertically center child text
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                ))
+                .with_child((
+                    LocalizedText("settings_menu.language"),
+                    TextShadow {
+                        offset: Vec2::splat(2.),
+                        color: Color::linear_rgba(0., 0., 0., 1.),
+                    },
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 33.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+
+            parent
+                .spawn((
+                    Button,
+                    SoundMenuButtonAction::ChangeLanguage,
+                    Node {
+                        width: Val::Percent(25.0),
+                        height: Val::Percent(10.0),
+                        top: Val::Percent(45.),
+                        left: Val::Percent(72.5),
+                        position_type: PositionType::Absolute,
+                        border: UiRect::all(Val::Px(5.0)),
+                        // horizontally center child text
+                        justify_content: JustifyContent::Center,
+                        // vertically center child text
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    BackgroundColor(NORMAL_BUTTON),
+                ))
+                .with_child((
+                    Text::new(localization_settings.language.to_string()),
+                    LanguageText,
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(50.0),
+                        height: Val::Percent(20.0),
+                        top: Val::Percent(60.),
+                        left: Val::Percent(10.),
+                        position_type: PositionType::Absolute,
+                        // horizontally center child text
+                        justify_content: JustifyContent::Center,
+                        // vertically center child text
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                ))
+                .with_child((
+                    LocalizedText("settings_menu.text_speed"),
+                    TextShadow {
+                        offset: Vec2::splat(2.),
+                        color: Color::linear_rgba(0., 0., 0., 1.),
+                    },
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 33.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+
+            parent
+                .spawn((
+                    Button,
+                    SoundMenuButtonAction::ChangeTextSpeed,
+                    Node {
+                        width: Val::Percent(25.0),
+                        height: Val::Percent(10.0),
+                        top: Val::Percent(65.),
+                        left: Val::Percent(72.5),
+                        position_type: PositionType::Absolute,
+                        border: UiRect::all(Val::Px(5.0)),
+                        // horizontally center child text
+                        justify_content: JustifyContent::Center,
+                        // vertically center child text
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    BackgroundColor(NORMAL_BUTTON),
+                ))
+                .with_child((
+                    LocalizedText(dialogue_settings.text_speed.get_localization_id()),
+                    TextSpeedText,
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(50.0),
+                        height: Val::Percent(20.0),
+                        top: Val::Percent(80.),
+                        left: Val::Percent(10.),
+                        position_type: PositionType::Absolute,
+                        // horizontally center child text
+                        justify_content: JustifyContent::Center,
+                        // vertically center child text
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                ))
+                .with_child((
+                    LocalizedText("settings_menu.voice_blips"),
+                    TextShadow {
+                        offset: Vec2::splat(2.),
+                        color: Color::linear_rgba(0., 0., 0., 1.),
+                    },
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 33.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+
+            parent
+                .spawn((
+                    Button,
+                    SoundMenuButtonAction::ToggleVoiceBlips,
+                    Node {
+                        width: Val::Percent(25.0),
+                        height: Val::Percent(10.0),
+                        top: Val::Percent(85.),
+                        left: Val::Percent(72.5),
+                        position_type: PositionType::Absolute,
+                        border: UiRect::all(Val::Px(5.0)),
+                        // horizontally center child text
+                        justify_content: JustifyContent::Center,
+                        // vertically center child text
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    BackgroundColor(NORMAL_BUTTON),
+                ))
+                .with_child((
+                    LocalizedText(get_toggle_localization_id(dialogue_settings.voice_blips)),
+                    VoiceBlipsText,
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
         });
 
+    main_parent
+        .spawn((
+            Button,
+            AudioSettingsComponent,
+            SoundMenuButtonAction::Controls,
+            Node {
+                width: Val::Percent(10.0),
+                height: Val::Percent(5.0),
+                top: Val::Percent(85.),
+                left: Val::Percent(controls_button_left),
+                position_type: PositionType::Absolute,
+                border: UiRect::all(Val::Px(5.0)),
+                // horizontally center child text
+                justify_content: JustifyContent::Center,
+                // vertically center child text
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BorderColor(Color::BLACK),
+            BorderRadius::MAX,
+            BackgroundColor(NORMAL_BUTTON),
+        ))
+        .with_child((
+            LocalizedText("settings_menu.controls"),
+            TextFont {
+                font: asset_server.load(DEFAULT_FONT),
+                font_size: 33.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+        ));
+
+    if !cweampuff_query.is_empty() {
+        main_parent
+            .spawn((
+                Button,
+                AudioSettingsComponent,
+                SoundMenuButtonAction::Backlog,
+                Node {
+                    width: Val::Percent(10.0),
+                    height: Val::Percent(5.0),
+                    top: Val::Percent(85.),
+                    left: Val::Percent(52.5),
+                    position_type: PositionType::Absolute,
+                    border: UiRect::all(Val::Px(5.0)),
+                    // horizontally center child text
+                    justify_content: JustifyContent::Center,
+                    // vertically center child text
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BorderColor(Color::BLACK),
+                BorderRadius::MAX,
+                BackgroundColor(NORMAL_BUTTON),
+            ))
+            .with_child((
+                LocalizedText("settings_menu.backlog"),
+                TextFont {
+                    font: asset_server.load(DEFAULT_FONT),
+                    font_size: 33.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+    }
+
     main_parent
         .spawn((
             Button,
@@ -415,7 +770,7 @@ pub fn spawn_audio_menu(
             Node {
                 width: Val::Percent(10.0),
                 height: Val::Percent(5.0),
-                top: Val::Percent(80.),
+                top: Val::Percent(92.5),
                 left: Val::Percent(45.),
                 position_type: PositionType::Absolute,
                 border: UiRect::all(Val::Px(5.0)),
@@ -430,7 +785,7 @@ pub fn spawn_audio_menu(
             BackgroundColor(NORMAL_BUTTON),
         ))
         .with_child((
-            Text::new("Back"),
+            LocalizedText("menu.back"),
             TextFont {
                 font: asset_server.load(DEFAULT_FONT),
                 font_size: 33.0,
@@ -442,9 +797,11 @@ pub fn spawn_audio_menu(
     
 }
 
+type AudioSettingsNodeFilter = (With<Node>, With<AudioSettingsComponent>, Without<Camera2d>, Without<FadeInFadeOutNode>);
+
 pub fn despawn_audio_settings(
     mut commands: Commands, 
-    query: Query<Entity, (With<Node>, With<AudioSettingsComponent>, Without<Camera2d>, Without<FadeInFadeOutNode>)>
+    query: Query<Entity, AudioSettingsNodeFilter>
 ) {
     for entity in query.iter() {
         commands.entity(entity).despawn();
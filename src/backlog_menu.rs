@@ -0,0 +1,298 @@
+use std::collections::VecDeque;
+
+use bevy::{color::Color, input::mouse::{AccumulatedMouseScroll, MouseScrollUnit}, prelude::*};
+
+use crate::{app_states::AppState, controls::{ActionInput, GameAction}, fade_in_fade_out::FadeInFadeOutNode, main_menu::{DEFAULT_FONT, NORMAL_BUTTON}, npc::{conversation_entry::Emotion, conversation_state::ConversationState, get_name_localization_id}};
+use crate::asset_loader::load_asset;
+use crate::localization::{Localization, LocalizedText};
+
+// How fast holding up or down scrolls through the backlog, in pixels per second.
+const SCROLL_SPEED: f32 = 900.;
+// Mouse wheels that scroll by lines are converted to pixels with this.
+const SCROLL_LINE_HEIGHT: f32 = 40.;
+const PORTRAIT_SIZE: f32 = 110.;
+// Older lines are dropped once the backlog is this long.
+const MAX_BACKLOG_ENTRIES: usize = 100;
+
+// A line that was shown during a conversation.
+pub struct BacklogEntry {
+    pub npc_name: String,
+    pub emotion: Emotion,
+    pub text: String
+}
+
+// The last lines shown since the game was started or loaded, oldest first.
+#[derive(Resource, Default)]
+pub struct DialogueBacklog(pub VecDeque<BacklogEntry>);
+
+impl DialogueBacklog {
+    pub fn push(&mut self, entry: BacklogEntry) {
+        if self.0.len() >= MAX_BACKLOG_ENTRIES {
+            self.0.pop_front();
+        }
+
+        self.0.push_back(entry);
+    }
+}
+
+#[derive(Component)]
+pub enum BacklogMenuButtonAction {
+    Back
+}
+
+#[derive(Component)]
+pub struct BacklogMenuComponent;
+
+#[derive(Component)]
+pub struct BacklogScrollNode;
+
+pub fn backlog_open_input_reader(
+    action_input: ActionInput,
+    mut app_state: ResMut<NextState<AppState>>,
+) {
+    if action_input.just_pressed(GameAction::Backlog) {
+        app_state.set(AppState::BacklogMenu);
+    }
+}
+
+// The backlog is opened from a conversation or from the pause menu, and goes back to where it was opened.
+fn close_backlog(app_state: &mut NextState<AppState>, conversation_state: &ConversationState) {
+    match conversation_state {
+        ConversationState::Started => app_state.set(AppState::InGame),
+        _ => app_state.set(AppState::AudioMenu)
+    }
+}
+
+type BacklogButtonInteraction<'a> = (&'a Interaction, &'a BacklogMenuButtonAction);
+
+pub fn backlog_button_interactions_handler(
+    interaction_query: Query<BacklogButtonInteraction, (Changed<Interaction>, With<Button>)>,
+    mut app_state: ResMut<NextState<AppState>>,
+    conversation_state: Res<State<ConversationState>>,
+) {
+    for (interaction, action) in interaction_query.iter() {
+        if let Interaction::Pressed = *interaction {
+            match action {
+                BacklogMenuButtonAction::Back => {
+                    close_backlog(&mut app_state, &conversation_state);
+
+                    return;
+                }
+            }
+        }
+    }
+}
+
+pub fn backlog_input_reader(
+    action_input: ActionInput,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    mut scroll_query: Query<&mut ScrollPosition, With<BacklogScrollNode>>,
+    mut app_state: ResMut<NextState<AppState>>,
+    conversation_state: Res<State<ConversationState>>,
+    time: Res<Time>,
+) {
+    if action_input.any_just_pressed([GameAction::Backlog, GameAction::Pause]) {
+        close_backlog(&mut app_state, &conversation_state);
+
+        return;
+    }
+
+    let mut scroll = match mouse_scroll.unit {
+        MouseScrollUnit::Line => -mouse_scroll.delta.y * SCROLL_LINE_HEIGHT,
+        MouseScrollUnit::Pixel => -mouse_scroll.delta.y
+    };
+
+    if action_input.pressed(GameAction::LookUp) {
+        scroll -= SCROLL_SPEED * time.delta_secs();
+    }
+    if action_input.pressed(GameAction::LookDown) {
+        scroll += SCROLL_SPEED * time.delta_secs();
+    }
+
+    if scroll == 0. {
+        return;
+    }
+
+    // The layout keeps the scroll position within the backlog, so there's no need to clamp it here.
+    for mut scroll_position in scroll_query.iter_mut() {
+        scroll_position.offset_y = (scroll_position.offset_y + scroll).max(0.);
+    }
+}
+
+pub fn spawn_backlog_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    dialogue_backlog: Res<DialogueBacklog>,
+    localization: Localization,
+) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::Srgba(Srgba { red: 0.1, green: 0.1, blue: 0.1, alpha: 0.95 })),
+        GlobalZIndex(1),
+        BacklogMenuComponent
+    )).with_children(|main_parent| {
+    main_parent
+        .spawn((Node {
+            width: Val::Percent(30.0),
+            height: Val::Percent(10.0),
+            top: Val::Percent(5.),
+            left: Val::Percent(35.),
+            position_type: PositionType::Absolute,
+            justify_content: JustifyContent::Center,
+            ..default()
+        }, BacklogMenuComponent))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    LocalizedText("backlog_menu.title"),
+                    TextShadow {
+                        offset: Vec2::splat(2.),
+                        color: Color::linear_rgba(0., 0., 0., 1.),
+                    },
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 40.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+        });
+
+    main_parent
+        .spawn((
+            Node {
+                width: Val::Percent(70.0),
+                height: Val::Percent(68.0),
+                top: Val::Percent(14.),
+                left: Val::Percent(15.),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(20.),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            // Opens scrolled all the way down, where the latest lines are.
+            ScrollPosition { offset_x: 0., offset_y: f32::MAX },
+            BacklogScrollNode,
+        ))
+        .with_children(|parent| {
+            if dialogue_backlog.0.is_empty() {
+                parent.spawn((
+                    LocalizedText("backlog_menu.empty"),
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 33.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                ));
+            }
+
+            for entry in dialogue_backlog.0.iter() {
+                parent
+                    .spawn(Node {
+                        width: Val::Percent(100.0),
+                        flex_shrink: 0.,
+                        column_gap: Val::Px(20.),
+                        align_items: AlignItems::Start,
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        // Narration has no speaker, so it takes up the space of the portrait too.
+                        let has_speaker = !entry.npc_name.is_empty();
+
+                        if has_speaker {
+                            row.spawn((
+                                ImageNode::new(load_asset(&asset_server, format!("npcs/{}/{}.png", entry.npc_name, entry.emotion))),
+                                Node {
+                                    width: Val::Px(PORTRAIT_SIZE),
+                                    height: Val::Px(PORTRAIT_SIZE),
+                                    flex_shrink: 0.,
+                                    ..default()
+                                },
+                            ));
+                        }
+
+                        row
+                            .spawn(Node {
+                                flex_direction: FlexDirection::Column,
+                                flex_grow: 1.,
+                                ..default()
+                            })
+                            .with_children(|column| {
+                                if has_speaker {
+                                    column.spawn((
+                                        Text::new(localization.get(&get_name_localization_id(&entry.npc_name))),
+                                        TextFont {
+                                            font: asset_server.load(DEFAULT_FONT),
+                                            font_size: 30.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::srgb(0.95, 0.8, 0.45)),
+                                    ));
+                                }
+
+                                column.spawn((
+                                    Text::new(entry.text.clone()),
+                                    TextFont {
+                                        font: asset_server.load(DEFAULT_FONT),
+                                        font_size: 36.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                                    TextLayout::new(JustifyText::Left, LineBreak::WordBoundary)
+                                ));
+                            });
+                    });
+            }
+        });
+
+    main_parent
+        .spawn((
+            Button,
+            BacklogMenuComponent,
+            BacklogMenuButtonAction::Back,
+            Node {
+                width: Val::Percent(10.0),
+                height: Val::Percent(5.0),
+                top: Val::Percent(87.),
+                left: Val::Percent(45.),
+                position_type: PositionType::Absolute,
+                border: UiRect::all(Val::Px(5.0)),
+                // horizontally center child text
+                justify_content: JustifyContent::Center,
+                // vertically center child text
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BorderColor(Color::BLACK),
+            BorderRadius::MAX,
+            BackgroundColor(NORMAL_BUTTON),
+        ))
+        .with_child((
+            LocalizedText("menu.back"),
+            TextFont {
+                font: asset_server.load(DEFAULT_FONT),
+                font_size: 33.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+        ));
+    });
+}
+
+type BacklogMenuNodeFilter = (With<Node>, With<BacklogMenuComponent>, Without<Camera2d>, Without<FadeInFadeOutNode>);
+
+pub fn despawn_backlog_menu(
+    mut commands: Commands,
+    query: Query<Entity, BacklogMenuNodeFilter>
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,6 +1,6 @@
 use bevy::{prelude::*, render::camera::ScalingMode};
 
-use crate::{level::LevelLayout, Cweampuff};
+use crate::{controls::{ActionInput, GameAction}, level::LevelLayout, Cweampuff};
 
 const CAMERA_TRANSFORM: Vec3 = Vec3::new(0.0, 3.0, 0.0);
 const CAMERA_DECAY_RATE: f32 = 10.;
@@ -30,7 +30,7 @@ pub fn spawn_camera (
 }
 
 pub fn cweampuff_camera_adjustment(
-    keyboard_input: Res<ButtonInput<KeyCode>>, 
+    action_input: ActionInput,
     cweampuff: Single<&Transform, (With<Cweampuff>, Without<Camera2d>)>,
     mut camera: Single<(&mut Transform, &mut CameraUpDownMovable), With<Camera2d>>,
     level_layout_query: Query<&LevelLayout, With<LevelLayout>>,
@@ -40,18 +40,18 @@ pub fn cweampuff_camera_adjustment(
 
     let time_passed = time.delta_secs();
 
-    if keyboard_input.any_just_released([KeyCode::ArrowUp, KeyCode::KeyW, KeyCode::ArrowDown, KeyCode::KeyS]) {
+    if action_input.any_just_released([GameAction::LookUp, GameAction::LookDown]) {
         camera_movable.look_up_down_duration = 0.;
     }
 
     let mut direction = 1.;
 
-    if keyboard_input.any_pressed([KeyCode::ArrowUp, KeyCode::KeyW, KeyCode::ArrowDown, KeyCode::KeyS]) {
+    if action_input.any_pressed([GameAction::LookUp, GameAction::LookDown]) {
         if camera_movable.look_up_down_duration < camera_movable.look_up_down_invoke_threshold {
             camera_movable.look_up_down_duration += time_passed;
         }
 
-        if keyboard_input.any_pressed([KeyCode::ArrowDown, KeyCode::KeyS]) {
+        if action_input.pressed(GameAction::LookDown) {
             direction = -1.;
         }
     }
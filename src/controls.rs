@@ -0,0 +1,301 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use serde::{Deserialize, Serialize};
+
+// Each action can be bound to a primary and a secondary key.
+pub const BINDING_SLOTS: usize = 2;
+// How far a stick has to be tilted before it counts as a pressed direction.
+pub const STICK_DEADZONE: f32 = 0.4;
+
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub enum GameAction {
+    MoveLeft,
+    MoveRight,
+    LookUp,
+    LookDown,
+    Jump,
+    Dash,
+    Interact,
+    Confirm,
+    Pause,
+    Backlog,
+    Skip
+}
+
+impl GameAction {
+    pub const ALL: [GameAction; 11] = [
+        GameAction::MoveLeft,
+        GameAction::MoveRight,
+        GameAction::LookUp,
+        GameAction::LookDown,
+        GameAction::Jump,
+        GameAction::Dash,
+        GameAction::Interact,
+        GameAction::Confirm,
+        GameAction::Pause,
+        GameAction::Backlog,
+        GameAction::Skip
+    ];
+
+    fn get_default_keys(&self) -> Vec<KeyCode> {
+        match self {
+            GameAction::MoveLeft => vec![KeyCode::ArrowLeft, KeyCode::KeyA],
+            GameAction::MoveRight => vec![KeyCode::ArrowRight, KeyCode::KeyD],
+            GameAction::LookUp => vec![KeyCode::ArrowUp, KeyCode::KeyW],
+            GameAction::LookDown => vec![KeyCode::ArrowDown, KeyCode::KeyS],
+            GameAction::Jump => vec![KeyCode::Space],
+            GameAction::Dash => vec![KeyCode::KeyX],
+            GameAction::Interact => vec![KeyCode::KeyE],
+            GameAction::Confirm => vec![KeyCode::Enter],
+            GameAction::Pause => vec![KeyCode::Escape],
+            GameAction::Backlog => vec![KeyCode::KeyB],
+            GameAction::Skip => vec![KeyCode::ControlLeft],
+        }
+    }
+
+    fn get_gamepad_buttons(&self) -> &'static [GamepadButton] {
+        match self {
+            GameAction::MoveLeft => &[GamepadButton::DPadLeft],
+            GameAction::MoveRight => &[GamepadButton::DPadRight],
+            GameAction::LookUp => &[GamepadButton::DPadUp],
+            GameAction::LookDown => &[GamepadButton::DPadDown],
+            GameAction::Jump => &[GamepadButton::South],
+            GameAction::Dash => &[GamepadButton::West, GamepadButton::RightTrigger],
+            GameAction::Interact => &[GamepadButton::North],
+            GameAction::Confirm => &[GamepadButton::South],
+            GameAction::Pause => &[GamepadButton::Start],
+            GameAction::Backlog => &[GamepadButton::Select],
+            GameAction::Skip => &[GamepadButton::LeftTrigger],
+        }
+    }
+
+    pub fn get_localization_id(&self) -> &'static str {
+        match self {
+            GameAction::MoveLeft => "controls.move_left",
+            GameAction::MoveRight => "controls.move_right",
+            GameAction::LookUp => "controls.look_up",
+            GameAction::LookDown => "controls.look_down",
+            GameAction::Jump => "controls.jump",
+            GameAction::Dash => "controls.dash",
+            GameAction::Interact => "controls.interact",
+            GameAction::Confirm => "controls.confirm",
+            GameAction::Pause => "controls.pause",
+            GameAction::Backlog => "controls.backlog",
+            GameAction::Skip => "controls.skip",
+        }
+    }
+
+    // Movement follows the left stick, looking up and down follows the right one.
+    fn is_stick_tilted(&self, gamepad: &Gamepad) -> bool {
+        let left_stick = gamepad.left_stick();
+        let right_stick = gamepad.right_stick();
+
+        match self {
+            GameAction::MoveLeft => left_stick.x < -STICK_DEADZONE,
+            GameAction::MoveRight => left_stick.x > STICK_DEADZONE,
+            GameAction::LookUp => right_stick.y > STICK_DEADZONE,
+            GameAction::LookDown => right_stick.y < -STICK_DEADZONE,
+            _ => false
+        }
+    }
+}
+
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ControlBindings {
+    bindings: HashMap<GameAction, Vec<KeyCode>>
+}
+
+impl Default for ControlBindings {
+    fn default() -> Self {
+        Self {
+            bindings: GameAction::ALL.iter().map(|action| (*action, action.get_default_keys())).collect()
+        }
+    }
+}
+
+impl ControlBindings {
+    // Actions missing from the settings file (e.g. added in a newer version) or left without keys get their default keys,
+    // minus the ones another action already uses. A key bound to more than one action stays with the first of them.
+    pub fn sanitized(mut self) -> Self {
+        let mut bound_keys = HashSet::new();
+
+        for action in GameAction::ALL {
+            let keys = self.bindings.entry(action).or_default();
+            keys.truncate(BINDING_SLOTS);
+            keys.retain(|key| bound_keys.insert(*key));
+        }
+
+        for action in GameAction::ALL {
+            let keys = self.bindings.entry(action).or_default();
+
+            if !keys.is_empty() {
+                continue;
+            }
+
+            let default_keys = action.get_default_keys();
+            let free_keys: Vec<KeyCode> = default_keys.iter()
+                .copied()
+                .filter(|key| !self.bindings.values().any(|keys| keys.contains(key)))
+                .collect();
+
+            self.bindings.insert(action, if free_keys.is_empty() { default_keys } else { free_keys });
+        }
+
+        self
+    }
+
+    pub fn get_keys(&self, action: GameAction) -> &[KeyCode] {
+        self.bindings.get(&action).map(|keys| keys.as_slice()).unwrap_or_default()
+    }
+
+    pub fn get_key(&self, action: GameAction, slot: usize) -> Option<KeyCode> {
+        self.get_keys(action).get(slot).copied()
+    }
+
+    // A key already bound somewhere else swaps places with the key it replaces, so one key never triggers two actions
+    // and no action is left without keys. Binding an empty slot to another action's only key is refused for the same reason.
+    pub fn rebind(&mut self, action: GameAction, slot: usize, key: KeyCode) {
+        let replaced_key = self.get_key(action, slot);
+
+        let current_binding = self.bindings.iter()
+            .find_map(|(bound_action, keys)| keys.iter().position(|bound_key| *bound_key == key).map(|index| (*bound_action, index)));
+
+        if let Some((bound_action, index)) = current_binding {
+            let keys = self.bindings.entry(bound_action).or_default();
+
+            match replaced_key {
+                Some(replaced_key) => keys[index] = replaced_key,
+                None if keys.len() > 1 || bound_action == action => { keys.remove(index); },
+                None => return
+            }
+        }
+
+        let keys = self.bindings.entry(action).or_default();
+
+        if slot < keys.len() {
+            keys[slot] = key;
+        }
+        else {
+            keys.push(key);
+        }
+    }
+
+    pub fn get_label(&self, action: GameAction) -> String {
+        match self.get_key(action, 0) {
+            Some(key) => get_key_label(key),
+            None => "-".to_string()
+        }
+    }
+}
+
+pub fn get_key_label(key: KeyCode) -> String {
+    let label = format!("{:?}", key);
+
+    label.strip_prefix("Key")
+        .or_else(|| label.strip_prefix("Digit"))
+        .unwrap_or(&label)
+        .to_string()
+}
+
+// Merges keyboard bindings, gamepad buttons and stick directions into a single pressed state per action,
+// so gameplay systems don't need to know which device the player is using.
+pub fn update_action_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    bindings: Res<ControlBindings>,
+    mut action_input: ResMut<ButtonInput<GameAction>>,
+) {
+    action_input.clear();
+
+    for action in GameAction::ALL {
+        let keys = bindings.get_keys(action);
+        let buttons = action.get_gamepad_buttons();
+
+        let is_pressed = keyboard_input.any_pressed(keys.iter().copied()) ||
+                         keyboard_input.any_just_pressed(keys.iter().copied()) ||
+                         gamepads.iter().any(|gamepad| {
+                            gamepad.any_pressed(buttons.iter().copied()) ||
+                            gamepad.any_just_pressed(buttons.iter().copied()) ||
+                            action.is_stick_tilted(gamepad)
+                         });
+
+        if is_pressed {
+            action_input.press(action);
+        }
+        else {
+            action_input.release(action);
+        }
+    }
+}
+
+#[derive(SystemParam)]
+pub struct ActionInput<'w> {
+    actions: Res<'w, ButtonInput<GameAction>>,
+}
+
+impl ActionInput<'_> {
+    pub fn pressed(&self, action: GameAction) -> bool {
+        self.actions.pressed(action)
+    }
+
+    pub fn just_pressed(&self, action: GameAction) -> bool {
+        self.actions.just_pressed(action)
+    }
+
+    pub fn just_released(&self, action: GameAction) -> bool {
+        self.actions.just_released(action)
+    }
+
+    pub fn any_pressed(&self, actions: impl IntoIterator<Item = GameAction>) -> bool {
+        self.actions.any_pressed(actions)
+    }
+
+    pub fn any_just_pressed(&self, actions: impl IntoIterator<Item = GameAction>) -> bool {
+        self.actions.any_just_pressed(actions)
+    }
+
+    pub fn any_just_released(&self, actions: impl IntoIterator<Item = GameAction>) -> bool {
+        self.actions.any_just_released(actions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebinding_to_a_bound_key_swaps_the_keys() {
+        let mut control_bindings = ControlBindings::default();
+        control_bindings.rebind(GameAction::Jump, 0, KeyCode::KeyX);
+
+        assert_eq!(control_bindings.get_keys(GameAction::Jump), [KeyCode::KeyX]);
+        assert_eq!(control_bindings.get_keys(GameAction::Dash), [KeyCode::Space]);
+    }
+
+    #[test]
+    fn rebinding_an_empty_slot_to_an_only_key_is_refused() {
+        let mut control_bindings = ControlBindings::default();
+        control_bindings.rebind(GameAction::Jump, 1, KeyCode::KeyX);
+
+        assert_eq!(control_bindings.get_keys(GameAction::Jump), [KeyCode::Space]);
+        assert_eq!(control_bindings.get_keys(GameAction::Dash), [KeyCode::KeyX]);
+    }
+
+    #[test]
+    fn sanitizing_keeps_a_key_bound_twice_on_the_first_action() {
+        let control_bindings = ron::from_str::<ControlBindings>("(bindings: {MoveLeft: [KeyA], Jump: [Space, KeyA]})").unwrap().sanitized();
+
+        assert_eq!(control_bindings.get_keys(GameAction::MoveLeft), [KeyCode::KeyA]);
+        assert_eq!(control_bindings.get_keys(GameAction::Jump), [KeyCode::Space]);
+    }
+
+    #[test]
+    fn sanitizing_gives_an_action_left_without_keys_its_free_default_keys() {
+        let control_bindings = ron::from_str::<ControlBindings>("(bindings: {MoveLeft: [KeyA, ArrowLeft], Dash: [ArrowLeft]})").unwrap().sanitized();
+
+        assert_eq!(control_bindings.get_keys(GameAction::MoveLeft), [KeyCode::KeyA, KeyCode::ArrowLeft]);
+        assert_eq!(control_bindings.get_keys(GameAction::Dash), [KeyCode::KeyX]);
+    }
+}
@@ -0,0 +1,307 @@
+use bevy::{color::Color, prelude::*};
+
+use crate::{app_states::AppState, controls::{get_key_label, ControlBindings, GameAction, BINDING_SLOTS}, fade_in_fade_out::FadeInFadeOutNode, main_menu::{DEFAULT_FONT, NORMAL_BUTTON}, settings::SettingsChangedEvent, Cweampuff};
+use crate::localization::LocalizedText;
+
+const ROWS_TOP: f32 = 13.;
+const ROW_SPACING: f32 = 6.5;
+
+#[derive(Component)]
+pub enum ControlsMenuButtonAction {
+    Rebind(GameAction, usize),
+    ResetToDefaults,
+    Back
+}
+
+#[derive(Component)]
+pub struct ControlsMenuComponent;
+
+#[derive(Component)]
+pub struct BindingText {
+    action: GameAction,
+    slot: usize
+}
+
+// The action and binding slot waiting for the next key press, if any.
+#[derive(Resource, Default)]
+pub struct PendingRebind(Option<(GameAction, usize)>);
+
+impl PendingRebind {
+    pub fn is_waiting(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+type ControlsMenuButtonInteraction<'a> = (&'a Interaction, &'a ControlsMenuButtonAction);
+
+pub fn controls_button_interactions_handler(
+    mut interaction_query: Query<ControlsMenuButtonInteraction, (Changed<Interaction>, With<Button>)>,
+    mut binding_texts: Query<(&mut Text, &BindingText)>,
+    mut app_state: ResMut<NextState<AppState>>,
+    mut pending_rebind: ResMut<PendingRebind>,
+    mut control_bindings: ResMut<ControlBindings>,
+    mut settings_writer: EventWriter<SettingsChangedEvent>,
+) {
+    for (interaction, action) in &mut interaction_query {
+        if let Interaction::Pressed = *interaction  {
+            match action {
+                ControlsMenuButtonAction::Rebind(game_action, slot) => {
+                    pending_rebind.0 = Some((*game_action, *slot));
+                },
+                ControlsMenuButtonAction::ResetToDefaults => {
+                    pending_rebind.0 = None;
+                    *control_bindings = ControlBindings::default();
+                    settings_writer.write(SettingsChangedEvent);
+                },
+                ControlsMenuButtonAction::Back => {
+                    pending_rebind.0 = None;
+                    app_state.set(AppState::AudioMenu);
+
+                    return;
+                },
+            };
+
+            update_binding_texts(&mut binding_texts, &control_bindings, &pending_rebind);
+        }
+    }
+}
+
+pub fn controls_rebind_input_reader(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut binding_texts: Query<(&mut Text, &BindingText)>,
+    mut pending_rebind: ResMut<PendingRebind>,
+    mut control_bindings: ResMut<ControlBindings>,
+    mut settings_writer: EventWriter<SettingsChangedEvent>,
+) {
+    let Some((action, slot)) = pending_rebind.0 else {
+        return;
+    };
+
+    let Some(key) = keyboard_input.get_just_pressed().next() else {
+        return;
+    };
+
+    // Escape cancels rebinding, so the pause key can't be lost by accident.
+    if *key != KeyCode::Escape {
+        control_bindings.rebind(action, slot, *key);
+        settings_writer.write(SettingsChangedEvent);
+    }
+
+    pending_rebind.0 = None;
+
+    update_binding_texts(&mut binding_texts, &control_bindings, &pending_rebind);
+}
+
+fn update_binding_texts(
+    binding_texts: &mut Query<(&mut Text, &BindingText)>,
+    control_bindings: &ControlBindings,
+    pending_rebind: &PendingRebind
+) {
+    for (mut text, binding_text) in binding_texts.iter_mut() {
+        **text = get_binding_label(control_bindings, pending_rebind, binding_text.action, binding_text.slot);
+    }
+}
+
+fn get_binding_label(control_bindings: &ControlBindings, pending_rebind: &PendingRebind, action: GameAction, slot: usize) -> String {
+    if pending_rebind.0 == Some((action, slot)) {
+        return "Press a key...".to_string();
+    }
+
+    match control_bindings.get_key(action, slot) {
+        Some(key) => get_key_label(key),
+        None => "-".to_string()
+    }
+}
+
+pub fn spawn_controls_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    control_bindings: Res<ControlBindings>,
+    pending_rebind: Res<PendingRebind>,
+    cweampuff_query: Query<Entity, With<Cweampuff>>,
+) {
+    let background_alpha = if cweampuff_query.is_empty() {
+        0.
+    }
+    else {
+        0.95
+    };
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::Srgba(Srgba { red: 0.1, green: 0.1, blue: 0.1, alpha: background_alpha })),
+        ControlsMenuComponent
+    )).with_children(|main_parent| {
+    main_parent
+        .spawn((Node {
+            width: Val::Percent(30.0),
+            height: Val::Percent(10.0),
+            top: Val::Percent(5.),
+            left: Val::Percent(35.),
+            position_type: PositionType::Absolute,
+            justify_content: JustifyContent::Center,
+            ..default()
+        }, ControlsMenuComponent))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    LocalizedText("controls_menu.title"),
+                    TextShadow {
+                        offset: Vec2::splat(2.),
+                        color: Color::linear_rgba(0., 0., 0., 1.),
+                    },
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 40.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+        });
+
+    for (index, action) in GameAction::ALL.into_iter().enumerate() {
+        let top = ROWS_TOP + index as f32 * ROW_SPACING;
+
+        main_parent
+            .spawn((
+                Node {
+                    width: Val::Percent(20.0),
+                    height: Val::Percent(5.0),
+                    top: Val::Percent(top),
+                    left: Val::Percent(25.),
+                    position_type: PositionType::Absolute,
+                    // vertically center child text
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+            ))
+            .with_child((
+                LocalizedText(action.get_localization_id()),
+                TextShadow {
+                    offset: Vec2::splat(2.),
+                    color: Color::linear_rgba(0., 0., 0., 1.),
+                },
+                TextFont {
+                    font: asset_server.load(DEFAULT_FONT),
+                    font_size: 33.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+
+        for slot in 0..BINDING_SLOTS {
+            main_parent
+                .spawn((
+                    Button,
+                    ControlsMenuButtonAction::Rebind(action, slot),
+                    Node {
+                        width: Val::Percent(12.0),
+                        height: Val::Percent(5.0),
+                        top: Val::Percent(top),
+                        left: Val::Percent(47. + slot as f32 * 14.),
+                        position_type: PositionType::Absolute,
+                        border: UiRect::all(Val::Px(5.0)),
+                        // horizontally center child text
+                        justify_content: JustifyContent::Center,
+                        // vertically center child text
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor(Color::BLACK),
+                    BorderRadius::MAX,
+                    BackgroundColor(NORMAL_BUTTON),
+                ))
+                .with_child((
+                    Text::new(get_binding_label(&control_bindings, &pending_rebind, action, slot)),
+                    BindingText { action, slot },
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+        }
+    }
+
+    main_parent
+        .spawn((
+            Button,
+            ControlsMenuComponent,
+            ControlsMenuButtonAction::ResetToDefaults,
+            Node {
+                width: Val::Percent(12.0),
+                height: Val::Percent(5.0),
+                top: Val::Percent(85.),
+                left: Val::Percent(36.),
+                position_type: PositionType::Absolute,
+                border: UiRect::all(Val::Px(5.0)),
+                // horizontally center child text
+                justify_content: JustifyContent::Center,
+                // vertically center child text
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BorderColor(Color::BLACK),
+            BorderRadius::MAX,
+            BackgroundColor(NORMAL_BUTTON),
+        ))
+        .with_child((
+            LocalizedText("controls_menu.reset"),
+            TextFont {
+                font: asset_server.load(DEFAULT_FONT),
+                font_size: 33.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+        ));
+
+    main_parent
+        .spawn((
+            Button,
+            ControlsMenuComponent,
+            ControlsMenuButtonAction::Back,
+            Node {
+                width: Val::Percent(12.0),
+                height: Val::Percent(5.0),
+                top: Val::Percent(85.),
+                left: Val::Percent(52.),
+                position_type: PositionType::Absolute,
+                border: UiRect::all(Val::Px(5.0)),
+                // horizontally center child text
+                justify_content: JustifyContent::Center,
+                // vertically center child text
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BorderColor(Color::BLACK),
+            BorderRadius::MAX,
+            BackgroundColor(NORMAL_BUTTON),
+        ))
+        .with_child((
+            LocalizedText("menu.back"),
+            TextFont {
+                font: asset_server.load(DEFAULT_FONT),
+                font_size: 33.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+        ));
+    });
+}
+
+type ControlsMenuNodeFilter = (With<Node>, With<ControlsMenuComponent>, Without<Camera2d>, Without<FadeInFadeOutNode>);
+
+pub fn despawn_controls_menu(
+    mut commands: Commands,
+    query: Query<Entity, ControlsMenuNodeFilter>
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
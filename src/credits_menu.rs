@@ -1,6 +1,7 @@
 use bevy::{color::Color, prelude::*};
 
 use crate::{app_states::AppState, fade_in_fade_out::FadeInFadeOutNode, main_menu::{DEFAULT_FONT, NORMAL_BUTTON}};
+use crate::localization::LocalizedText;
 
 #[derive(Component)]
 pub enum CreditsMenuButtonAction {
@@ -10,8 +11,10 @@ pub enum CreditsMenuButtonAction {
 #[derive(Component)]
 pub struct CreditsMenuComponent;
 
+type CreditsMenuButtonInteraction<'a> = (&'a Interaction, &'a CreditsMenuButtonAction);
+
 pub fn credits_button_interactions_handler(
-    mut interaction_query: Query<(&Interaction, &CreditsMenuButtonAction), (Changed<Interaction>, With<Button>)>,
+    mut interaction_query: Query<CreditsMenuButtonInteraction, (Changed<Interaction>, With<Button>)>,
     mut app_state: ResMut<NextState<AppState>>,
 ) {
     for (interaction, action) in &mut interaction_query {
@@ -52,7 +55,7 @@ pub fn spawn_credits_menu(
         .with_children(|parent| {
             parent
                 .spawn((
-                    Text::new("Credits"),
+                    LocalizedText("credits_menu.title"),
                     TextShadow {
                         offset: Vec2::splat(2.),
                         color: Color::linear_rgba(0., 0., 0., 1.),
@@ -92,7 +95,7 @@ pub fn spawn_credits_menu(
                     },
                 ))
                 .with_child((
-                    Text::new("-Game design, Programming, Story-"),
+                    LocalizedText("credits_menu.game_design"),
                     TextShadow {
                         offset: Vec2::splat(2.),
                         color: Color::linear_rgba(0., 0., 0., 1.),
@@ -150,7 +153,7 @@ pub fn spawn_credits_menu(
                     },
                 ))
                 .with_child((
-                    Text::new("-Code review, Story editing-"),
+                    LocalizedText("credits_menu.code_review"),
                     TextShadow {
                         offset: Vec2::splat(2.),
                         color: Color::linear_rgba(0., 0., 0., 1.),
@@ -208,7 +211,7 @@ pub fn spawn_credits_menu(
                     },
                 ))
                 .with_child((
-                    Text::new("-Amazing Art-"),
+                    LocalizedText("credits_menu.art"),
                     TextShadow {
                         offset: Vec2::splat(2.),
                         color: Color::linear_rgba(0., 0., 0., 1.),
@@ -266,7 +269,7 @@ pub fn spawn_credits_menu(
                     },
                 ))
                 .with_child((
-                    Text::new("-Banger Music-"),
+                    LocalizedText("credits_menu.music"),
                     TextShadow {
                         offset: Vec2::splat(2.),
                         color: Color::linear_rgba(0., 0., 0., 1.),
@@ -332,7 +335,7 @@ pub fn spawn_credits_menu(
             BackgroundColor(NORMAL_BUTTON),
         ))
         .with_child((
-            Text::new("Back"),
+            LocalizedText("menu.back"),
             TextFont {
                 font: asset_server.load(DEFAULT_FONT),
                 font_size: 33.0,
@@ -344,9 +347,11 @@ pub fn spawn_credits_menu(
     
 }
 
+type CreditsMenuNodeFilter = (With<Node>, With<CreditsMenuComponent>, Without<Camera2d>, Without<FadeInFadeOutNode>);
+
 pub fn despawn_credits_menu(
     mut commands: Commands, 
-    query: Query<Entity, (With<Node>, With<CreditsMenuComponent>, Without<Camera2d>, Without<FadeInFadeOutNode>)>
+    query: Query<Entity, CreditsMenuNodeFilter>
 ) {
     for entity in query.iter() {
         commands.entity(entity).despawn();
@@ -1,8 +1,10 @@
 use bevy::asset::{LoadState, UntypedAssetId};
 use bevy::audio::{PlaybackMode, Volume};
 use bevy::ecs::event::{Event, EventReader};
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::ui::widget::NodeImageMode;
+use serde::{Deserialize, Serialize};
 
 use crate::app_states::AppState;
 use crate::fade_in_fade_out::{FadeInFadeOutNode, FadeState};
@@ -11,16 +13,18 @@ use crate::level::progression::Progression;
 use crate::level::transition_states::TransitionState;
 use crate::level::{manually_transition_to_level, Level, LevelLayout};
 use crate::main_menu::DEFAULT_FONT;
-use crate::npc::{LeftCharacterImageNode, RightCharacterImageNode};
+use crate::npc::CharacterImageNode;
 use crate::{Cweampuff, CWEAMPUFF_STARTING_POSITION};
 use crate::asset_loader::load_asset;
+use crate::controls::{ActionInput, GameAction};
+use crate::localization::Localization;
 
 #[derive(Component)]
 pub struct LoadingAssets{
     assets: Vec<UntypedAssetId>
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize, Deserialize)]
 pub enum PostCutsceneAction {
     TransitionTo(Level),
     EndGame
@@ -28,20 +32,28 @@ pub enum PostCutsceneAction {
 
 #[derive(Event)]
 pub enum CutsceneEvent {
-    Started(&'static[CutsceneInfo], &'static str, PostCutsceneAction),
+    Started(Vec<CutsceneInfo>, String, PostCutsceneAction),
     Stopped(Cweampuff, PostCutsceneAction, Vec3)
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct CutsceneInfo {
-    pub text: &'static str,
-    pub background: &'static str
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub text: String,
+    pub background: String
+}
+
+impl CutsceneInfo {
+    pub fn new(text: &str, background: &str) -> Self {
+        CutsceneInfo { id: None, text: text.to_string(), background: background.to_string() }
+    }
 }
 
 #[derive(Component)]
 pub struct Cutscene {
-    infos: &'static[CutsceneInfo],
-    bgm: &'static str,
+    infos: Vec<CutsceneInfo>,
+    bgm: String,
     current_index: usize,
     post_cutscene_action: PostCutsceneAction
 }
@@ -65,7 +77,7 @@ pub fn cutscene_event_reader(
 ) {
     for cutscene in cutscene_events.read() {
         if let CutsceneEvent::Started(infos, bgm, level) = cutscene {
-            commands.spawn(Cutscene { infos, bgm, current_index: 0, post_cutscene_action: *level});
+            commands.spawn(Cutscene { infos: infos.clone(), bgm: bgm.clone(), current_index: 0, post_cutscene_action: *level});
 
             state.set(AppState::Cutscene);
             next_bgm_state.set(LevelBGMState::Changing);
@@ -86,7 +98,7 @@ pub fn cutscene_event_reader(
 }
 
 pub fn cutscene_input_reader(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    action_input: ActionInput,
     mouse_input: Res<ButtonInput<MouseButton>>, 
     current_cutscene: Single<&Cutscene, With<Cutscene>>,
     mut next_fade_state: ResMut<NextState<FadeState>>,
@@ -94,7 +106,7 @@ pub fn cutscene_input_reader(
     mut next_bgm_state: ResMut<NextState<LevelBGMState>>,
 ) {
     if current_cutscene.current_index == 0 || 
-       keyboard_input.any_just_pressed([KeyCode::Space, KeyCode::Enter]) ||
+       action_input.any_just_pressed([GameAction::Jump, GameAction::Confirm]) ||
        mouse_input.any_just_pressed([MouseButton::Left, MouseButton::Right]) {
         match current_fade_state.get() {
             FadeState::FadeIn | FadeState::FadeInFinished | FadeState::FadeOut => (),
@@ -109,16 +121,42 @@ pub fn cutscene_input_reader(
     }
 }
 
+// The background and text of the cutscene, a slide is shown once its background has loaded.
+#[derive(SystemParam)]
+pub struct CutsceneSlide<'w, 's> {
+    text_query: Query<'w, 's, &'static mut Text, With<CutsceneText>>,
+    background_image: Single<'w, &'static mut ImageNode, (With<Node>, With<CutsceneBackground>, Without<CharacterImageNode>)>,
+    loading_assets: Single<'w, &'static mut LoadingAssets, With<LoadingAssets>>,
+    asset_server: Res<'w, AssetServer>,
+    localization: Localization<'w>,
+}
+
+impl CutsceneSlide<'_, '_> {
+    fn show(&mut self, info: &CutsceneInfo) {
+        if !info.background.is_empty() {
+            self.background_image.image = load_asset(&self.asset_server, &info.background);
+        }
+        else {
+            self.background_image.image = load_asset(&self.asset_server, "cutscenes/placeholder.png");
+        }
+
+        self.loading_assets.assets.push(self.background_image.image.id().untyped());
+
+        let text = self.localization.get_or(info.id.as_deref(), &info.text);
+
+        for mut cutscene_text in self.text_query.iter_mut() {
+            **cutscene_text = text.clone();
+        }
+    }
+}
+
 pub fn cutscene_player(
     mut cutscene_events: EventWriter<CutsceneEvent>, 
-    mut text_query: Query<&mut Text, With<CutsceneText>>,
-    mut background_image: Single<&mut ImageNode, (With<Node>, With<CutsceneBackground>, Without<RightCharacterImageNode>, Without<LeftCharacterImageNode>)>,
     mut current_cutscene: Single<&mut Cutscene, With<Cutscene>>,
     cweampuff_query: Query<(&Cweampuff, &Transform), With<Cweampuff>>,
-    asset_server: Res<AssetServer>,
-    mut loading_assets: Single<&mut LoadingAssets, With<LoadingAssets>>,
     mut commands: Commands,
     bgm_query: Query<Entity, (With<LevelBGM>, Without<CutsceneAudio>)>,
+    mut cutscene_slide: CutsceneSlide,
 ) {    
     if current_cutscene.current_index == 0 {
         for entity in bgm_query.iter() {
@@ -147,18 +185,7 @@ pub fn cutscene_player(
             }
         };
 
-        if !current_cutscene_info.background.is_empty() {
-            background_image.image = load_asset(&asset_server, current_cutscene_info.background);
-        }
-        else {
-            background_image.image = load_asset(&asset_server, "cutscenes/placeholder.png");
-        }
-        
-        loading_assets.assets.push(background_image.image.id().untyped());
-
-        for mut text in text_query.iter_mut() {
-            **text = current_cutscene_info.text.to_string();
-        }
+        cutscene_slide.show(current_cutscene_info);
     }
     
     current_cutscene.current_index += 1;
@@ -234,7 +261,7 @@ pub fn spawn_cutscene_resources(
     playback_settings.mode = PlaybackMode::Loop;
 
     commands.spawn((
-        AudioPlayer::new(load_asset(&asset_server, current_cutscene.bgm)),
+        AudioPlayer::new(load_asset(&asset_server, &current_cutscene.bgm)),
         LevelBGM,
         CutsceneAudio,
         playback_settings
@@ -0,0 +1,142 @@
+use bevy::{audio::{PlaybackMode, Volume}, ecs::system::SystemParam, prelude::*};
+use bevy_rapier2d::prelude::*;
+
+use crate::{audio_settings::AudioSettings, camera::get_adjusted_camera_position, fade_in_fade_out::FadeState, health::Health, level::{manually_transition_to_level, transition_states::TransitionState, Level, LevelLayout}, movement::{Jumper, Movable}, Cweampuff, CWEAMPUFF_GRAVITY_SCALE, CWEAMPUFF_SPRITE_SIZE};
+use crate::asset_loader::load_asset;
+
+const DEATH_ANIMATION_SECS: f32 = 0.6;
+// Full turns the Cweampuff spins while it shrinks away.
+const DEATH_ANIMATION_TURNS: f32 = 1.5;
+
+#[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeathState {
+    #[default]
+    Alive,
+    Dying
+}
+
+// Where the Cweampuff comes back after running out of health.
+// That's the last checkpoint it rested at, or wherever it last entered a level if it hasn't rested anywhere yet.
+#[derive(Resource, Default)]
+pub struct RespawnPoint {
+    pub position: Vec3,
+    pub checkpoint_level: Option<Level>
+}
+
+#[derive(Resource)]
+pub struct DeathAnimationTimer(Timer);
+
+pub fn update_respawn_point(
+    cweampuff: Query<&Transform, With<Cweampuff>>,
+    mut respawn_point: ResMut<RespawnPoint>,
+) {
+    if respawn_point.checkpoint_level.is_some() {
+        return;
+    }
+
+    if let Ok(transform) = cweampuff.single() {
+        respawn_point.position = transform.translation;
+    }
+}
+
+pub fn start_dying(
+    mut cweampuff: Single<(&mut Velocity, &mut GravityScale, &mut Health), With<Cweampuff>>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    audio_settings: Res<AudioSettings>,
+) {
+    let (cweampuff_velocity, cweampuff_gravity, cweampuff_health) = &mut *cweampuff;
+
+    cweampuff_velocity.linvel = Vec2::new(0., 0.);
+    cweampuff_gravity.0 = 0.;
+    cweampuff_health.stop_touching_everything();
+
+    let mut playback_settings = PlaybackSettings::default().with_volume(Volume::Linear(audio_settings.sfx_volume));
+    playback_settings.mode = PlaybackMode::Despawn;
+
+    commands.spawn((
+        AudioPlayer::new(load_asset(&asset_server, "sfx/death.wav")),
+        playback_settings
+    ));
+
+    commands.insert_resource(DeathAnimationTimer(Timer::from_seconds(DEATH_ANIMATION_SECS, TimerMode::Once)));
+}
+
+pub fn death_animation(
+    mut cweampuff: Single<(&mut Sprite, &mut Transform), With<Cweampuff>>,
+    mut death_animation_timer: ResMut<DeathAnimationTimer>,
+    mut fade_state: ResMut<NextState<FadeState>>,
+    time: Res<Time>,
+) {
+    let (cweampuff_sprite, cweampuff_transform) = &mut *cweampuff;
+
+    death_animation_timer.0.tick(time.delta());
+
+    let progress = death_animation_timer.0.fraction();
+
+    cweampuff_sprite.custom_size = Some(CWEAMPUFF_SPRITE_SIZE * (1. - progress));
+    cweampuff_transform.rotation = Quat::from_rotation_z(progress * DEATH_ANIMATION_TURNS * std::f32::consts::TAU);
+
+    if death_animation_timer.0.finished() {
+        fade_state.set(FadeState::FadeIn);
+    }
+}
+
+// Where the respawn takes the Cweampuff, which might be a checkpoint in another level.
+#[derive(SystemParam)]
+pub struct RespawnDestination<'w, 's> {
+    respawn_point: Res<'w, RespawnPoint>,
+    level_layout_query: Query<'w, 's, &'static LevelLayout, With<LevelLayout>>,
+    current_level_layout: Query<'w, 's, Entity, With<LevelLayout>>,
+    transition_state: ResMut<'w, NextState<TransitionState>>,
+}
+
+impl RespawnDestination<'_, '_> {
+    // The other level is reached through the same transition doors use, which places the Cweampuff at the checkpoint once it's loaded.
+    fn transition_to_other_level(&mut self, cweampuff: &Cweampuff, commands: &mut Commands) -> bool {
+        let current_level = self.level_layout_query.iter().next().map(|level_layout| level_layout.level);
+
+        let Some(checkpoint_level) = self.respawn_point.checkpoint_level.filter(|level| Some(*level) != current_level) else {
+            return false;
+        };
+
+        manually_transition_to_level(&self.current_level_layout, &mut self.transition_state, cweampuff, commands, checkpoint_level, self.respawn_point.position);
+
+        true
+    }
+}
+
+type RespawnedCweampuff<'a> = (&'a Cweampuff, &'a mut Transform, &'a mut Sprite, &'a mut Velocity, &'a mut GravityScale, &'a mut Jumper, &'a mut Movable, &'a mut Health);
+
+// Runs behind the fade, the abilities are reset right after.
+pub fn respawn_cweampuff(
+    mut cweampuff: Single<RespawnedCweampuff, (With<Cweampuff>, Without<Camera2d>)>,
+    mut camera: Single<&mut Transform, (With<Camera2d>, Without<Cweampuff>)>,
+    mut respawn_destination: RespawnDestination,
+    mut death_state: ResMut<NextState<DeathState>>,
+    mut fade_state: ResMut<NextState<FadeState>>,
+    mut commands: Commands,
+) {
+    let (cweampuff, cweampuff_transform, cweampuff_sprite, cweampuff_velocity, cweampuff_gravity, cweampuff_jumper, cweampuff_movable, cweampuff_health) = &mut *cweampuff;
+
+    cweampuff_transform.rotation = Quat::IDENTITY;
+    cweampuff_sprite.custom_size = Some(CWEAMPUFF_SPRITE_SIZE);
+
+    cweampuff_velocity.linvel = Vec2::new(0., 0.);
+    cweampuff_gravity.0 = CWEAMPUFF_GRAVITY_SCALE;
+    cweampuff_jumper.jump_impulse = cweampuff_jumper.jump_impulse.abs();
+    cweampuff_movable.is_upside_down = false;
+    cweampuff_health.heal();
+
+    commands.remove_resource::<DeathAnimationTimer>();
+    death_state.set(DeathState::Alive);
+
+    if respawn_destination.transition_to_other_level(cweampuff, &mut commands) {
+        return;
+    }
+
+    cweampuff_transform.translation = respawn_destination.respawn_point.position;
+    camera.translation = get_adjusted_camera_position(cweampuff_transform, &respawn_destination.level_layout_query, None);
+
+    fade_state.set(FadeState::FadeOut);
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// The speeds that can be picked in the settings menu, other speeds can still be set in the settings file.
+pub const TEXT_SPEED_PRESETS: [TextSpeed; 4] = [
+    TextSpeed::CharsPerSecond(30.),
+    TextSpeed::CharsPerSecond(60.),
+    TextSpeed::CharsPerSecond(120.),
+    TextSpeed::Instant
+];
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TextSpeed {
+    CharsPerSecond(f32),
+    Instant
+}
+
+impl TextSpeed {
+    pub fn get_next(&self) -> TextSpeed {
+        let next_preset = match self {
+            TextSpeed::CharsPerSecond(chars_per_second) => TEXT_SPEED_PRESETS.iter().find(|preset| match preset {
+                TextSpeed::CharsPerSecond(preset_chars_per_second) => preset_chars_per_second > chars_per_second,
+                TextSpeed::Instant => true
+            }),
+            TextSpeed::Instant => None
+        };
+
+        next_preset.copied().unwrap_or(TEXT_SPEED_PRESETS[0])
+    }
+
+    pub fn get_localization_id(&self) -> &'static str {
+        match TEXT_SPEED_PRESETS.iter().position(|preset| preset == self) {
+            Some(0) => "text_speed.slow",
+            Some(1) => "text_speed.normal",
+            Some(2) => "text_speed.fast",
+            Some(_) => "text_speed.instant",
+            None => "text_speed.custom"
+        }
+    }
+}
+
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DialogueSettings {
+    pub text_speed: TextSpeed,
+    pub voice_blips: bool
+}
+
+impl DialogueSettings {
+    // A hand edited settings file could ask for text that never shows up.
+    pub fn sanitized(self) -> Self {
+        match self.text_speed {
+            TextSpeed::CharsPerSecond(chars_per_second) if !chars_per_second.is_finite() || chars_per_second <= 0. => Self {
+                text_speed: Self::default().text_speed,
+                ..self
+            },
+            _ => self
+        }
+    }
+}
+
+impl Default for DialogueSettings {
+    fn default() -> Self {
+        Self {
+            text_speed: TEXT_SPEED_PRESETS[1],
+            voice_blips: true
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use bevy::{audio::{PlaybackMode, Volume}, prelude::*};
+use bevy_rapier2d::prelude::*;
+
+use crate::{audio_settings::AudioSettings, death::DeathState, movement::Movable, Cweampuff};
+use crate::asset_loader::load_asset;
+
+const INVULNERABILITY_SECS: f32 = 1.2;
+// How many times per second the Cweampuff blinks while it can't be hurt.
+const FLASH_RATE: f32 = 12.;
+const KNOCKBACK_VELOCITY: Vec2 = Vec2::new(450., 550.);
+const HEART_SIZE: f32 = 48.;
+const FULL_HEART_COLOR: Color = Color::srgb(0.9, 0.15, 0.25);
+const EMPTY_HEART_COLOR: Color = Color::srgba(0.2, 0.2, 0.2, 0.8);
+
+#[derive(Component)]
+pub struct Health {
+    pub current: u32,
+    pub max: u32,
+    time_passed_since_hit: Option<f32>,
+    // What the Cweampuff is still standing in, it gets hurt again as soon as it can be.
+    touched_sources: Vec<(Entity, DamageEvent)>
+}
+
+impl Health {
+    pub fn new(max: u32) -> Self {
+        Health { current: max, max, time_passed_since_hit: None, touched_sources: Vec::new() }
+    }
+
+    pub fn start_touching(&mut self, source: Entity, damage: DamageEvent) {
+        self.touched_sources.push((source, damage));
+    }
+
+    pub fn stop_touching(&mut self, source: Entity) {
+        self.touched_sources.retain(|(touched_source, _)| *touched_source != source);
+    }
+
+    // Dying takes the Cweampuff out of whatever it was standing in, so it respawns unhurt.
+    pub fn stop_touching_everything(&mut self) {
+        self.touched_sources.clear();
+    }
+
+    pub fn heal(&mut self) {
+        self.current = self.max;
+    }
+
+    pub fn is_invulnerable(&self) -> bool {
+        self.time_passed_since_hit.is_some()
+    }
+}
+
+// Hurts the Cweampuff and knocks it away from `source`.
+#[derive(Event, Clone, Copy)]
+pub struct DamageEvent {
+    pub amount: u32,
+    pub source: Vec3
+}
+
+#[derive(Component)]
+pub struct HealthHud;
+
+// Index of the heart within the HUD, the first `current` hearts are full.
+#[derive(Component)]
+pub struct HeartImage(u32);
+
+pub fn damage_event_reader(
+    mut damage_events: EventReader<DamageEvent>,
+    mut cweampuff: Single<(&Transform, &mut Health, &mut Velocity, &mut Movable), With<Cweampuff>>,
+    mut death_state: ResMut<NextState<DeathState>>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    audio_settings: Res<AudioSettings>,
+) {
+    let (cweampuff_transform, cweampuff_health, cweampuff_velocity, cweampuff_movable) = &mut *cweampuff;
+
+    for damage_event in damage_events.read() {
+        if cweampuff_health.is_invulnerable() || cweampuff_health.current == 0 {
+            continue;
+        }
+
+        cweampuff_health.current = cweampuff_health.current.saturating_sub(damage_event.amount);
+
+        if cweampuff_health.current == 0 {
+            death_state.set(DeathState::Dying);
+
+            return;
+        }
+
+        cweampuff_health.time_passed_since_hit = Some(0.);
+
+        let direction = if cweampuff_transform.translation.x < damage_event.source.x { -1. } else { 1. };
+        let up = if cweampuff_movable.is_upside_down { -1. } else { 1. };
+
+        cweampuff_velocity.linvel = Vec2::new(KNOCKBACK_VELOCITY.x * direction, KNOCKBACK_VELOCITY.y * up);
+        cweampuff_movable.is_stunlocked = true;
+        cweampuff_movable.time_passed_since_stun = 0.;
+
+        let mut playback_settings = PlaybackSettings::default().with_volume(Volume::Linear(audio_settings.sfx_volume));
+        playback_settings.mode = PlaybackMode::Despawn;
+
+        commands.spawn((
+            AudioPlayer::new(load_asset(&asset_server, "sfx/hurt.wav")),
+            playback_settings
+        ));
+    }
+}
+
+pub fn invulnerability_monitor(
+    mut cweampuff: Single<(&mut Health, &mut Sprite), With<Cweampuff>>,
+    mut damage_writer: EventWriter<DamageEvent>,
+    time: Res<Time>,
+) {
+    let (cweampuff_health, cweampuff_sprite) = &mut *cweampuff;
+
+    let Some(time_passed_since_hit) = cweampuff_health.time_passed_since_hit else {
+        return;
+    };
+
+    let time_passed_since_hit = time_passed_since_hit + time.delta_secs();
+
+    if time_passed_since_hit >= INVULNERABILITY_SECS {
+        cweampuff_health.time_passed_since_hit = None;
+        cweampuff_sprite.color.set_alpha(1.);
+
+        if let Some((_, damage)) = cweampuff_health.touched_sources.first() {
+            damage_writer.write(*damage);
+        }
+
+        return;
+    }
+
+    cweampuff_health.time_passed_since_hit = Some(time_passed_since_hit);
+
+    let is_visible = (time_passed_since_hit * FLASH_RATE) as u32 % 2 == 1;
+    cweampuff_sprite.color.set_alpha(if is_visible { 1. } else { 0.3 });
+}
+
+// Dying or respawning shouldn't leave the Cweampuff blinking.
+pub fn end_invulnerability(
+    mut cweampuff: Single<(&mut Health, &mut Sprite), With<Cweampuff>>,
+) {
+    let (cweampuff_health, cweampuff_sprite) = &mut *cweampuff;
+
+    cweampuff_health.time_passed_since_hit = None;
+    cweampuff_health.touched_sources.clear();
+    cweampuff_sprite.color.set_alpha(1.);
+}
+
+pub fn spawn_health_hud(
+    mut commands: Commands,
+) {
+    commands.spawn((
+        Node {
+            top: Val::Px(20.),
+            left: Val::Px(20.),
+            column_gap: Val::Px(8.),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        HealthHud
+    ));
+}
+
+// Hearts are added once the Cweampuff exists, since that's where the number of hit points comes from.
+pub fn update_health_hud(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    cweampuff: Query<&Health, With<Cweampuff>>,
+    hud_query: Query<Entity, With<HealthHud>>,
+    mut heart_query: Query<(&mut ImageNode, &HeartImage)>,
+) {
+    let Ok(health) = cweampuff.single() else {
+        return;
+    };
+
+    let Ok(hud) = hud_query.single() else {
+        return;
+    };
+
+    let heart_count = heart_query.iter().count() as u32;
+
+    for index in heart_count..health.max {
+        commands.spawn((
+            ImageNode::new(load_asset(&asset_server, "ui/heart.png"))
+                .with_color(if index < health.current { FULL_HEART_COLOR } else { EMPTY_HEART_COLOR }),
+            Node {
+                width: Val::Px(HEART_SIZE),
+                height: Val::Px(HEART_SIZE),
+                ..default()
+            },
+            HeartImage(index),
+            ChildOf(hud)
+        ));
+    }
+
+    for (mut heart_image, HeartImage(index)) in heart_query.iter_mut() {
+        let color = if *index < health.current { FULL_HEART_COLOR } else { EMPTY_HEART_COLOR };
+
+        if heart_image.color != color {
+            heart_image.color = color;
+        }
+    }
+}
+
+pub fn despawn_health_hud(
+    mut commands: Commands,
+    hud_query: Query<Entity, With<HealthHud>>,
+) {
+    for entity in hud_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
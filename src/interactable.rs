@@ -2,14 +2,16 @@ pub mod interaction_state;
 
 use bevy::prelude::*;
 
-use crate::main_menu::DEFAULT_FONT;
+use crate::{controls::{ControlBindings, GameAction}, localization::Localization, main_menu::DEFAULT_FONT};
 
 #[derive(Component)]
 pub struct Interactable;
 
 pub fn spawn_interaction_prompt(
     mut commands: Commands,
-    asset_server: Res<AssetServer>
+    asset_server: Res<AssetServer>,
+    control_bindings: Res<ControlBindings>,
+    localization: Localization,
 ) {
     commands
         .spawn((
@@ -24,7 +26,7 @@ pub fn spawn_interaction_prompt(
         .with_children(|parent| {
             parent
                 .spawn((
-                    Text::new("[E]: Interact"),
+                    Text::new(localization.get("prompt.interact").replace("{key}", &control_bindings.get_label(GameAction::Interact))),
                     TextFont {
                         font: asset_server.load(DEFAULT_FONT),
                         font_size: 50.0,
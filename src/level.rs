@@ -1,28 +1,22 @@
 use bevy::{
+    asset::io::file::FileAssetReader,
     audio::{PlaybackMode, Volume},
     ecs::observer::TriggerTargets,
     prelude::*,
 };
 use bevy_rapier2d::prelude::*;
 use level_bgm::LevelBGM;
-use std::{collections::HashMap, sync::LazyLock};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Display, path::PathBuf};
 use transition_states::TransitionState;
 
-use crate::{animations::AnimationConfig, interactable::interaction_state::InteractionState};
+use crate::{animations::AnimationConfig, app_states::AppState, fade_in_fade_out::FadeState, interactable::interaction_state::InteractionState};
+use crate::level::floor_modification::CrumblingPlatformSprite;
+use crate::level::level_asset::LevelAsset;
 use crate::level::level_layout::{
-    aquwa_lair_layout::AquwaLairInfo, cerber_lair_layout::CerberLairInfo,
-    cweamcat_house_layout::CweamcatHouseInfo, cweamcat_lair_layout::CweamcatLairInfo,
-    factory_1_layout::Factory1Info, factory_2_layout::Factory2Info, factory_3_layout::Factory3Info,
-    factory_4_layout::Factory4Info, factory_hidden_level_layout::FactoryHiddenLevelInfo,
-    factory_transition_layout::FactoryTransitionInfo, hell_1_layout::Hell1Info,
-    hell_2_layout::Hell2Info, hell_3_layout::Hell3Info, hell_4_layout::Hell4Info,
-    neuro_lair_layout::NeuroLairInfo, spaceship_1_layout::Spaceship1Info,
-    spaceship_2_layout::Spaceship2Info, spaceship_3_layout::Spaceship3Info,
-    spaceship_4_layout::Spaceship4Info, starting_room_layout::StartingRoomInfo, DoorCollider,
-    DoorType, FloorAssetType, FloorCollider, FloorInfo, FloorModification, LevelInfo,
-    TransitionCollider,
+    DoorCollider, DoorType, FloorAssetType, FloorCollider, FloorInfo, FloorModification, HazardType, TransitionCollider,
 };
-use crate::npc::{MILK, MILK_ASLEEP};
+use crate::npc::{dialogue_asset::{DialogueAsset, DialogueMap}, DialogueFlags, MILK, MILK_ASLEEP};
 use crate::CWEAMPUFF_GRAVITY_SCALE;
 use crate::{
     camera::get_adjusted_camera_position, interactable::Interactable, npc::NPC, Cweampuff,
@@ -31,54 +25,53 @@ use crate::asset_loader::load_asset;
 
 pub mod cheats;
 pub mod door;
+pub mod editor;
 pub mod floor_modification;
+pub mod level_asset;
 pub mod level_bgm;
 pub mod level_layout;
 pub mod progression;
+pub mod tiled_import;
 pub mod transition_states;
 
 const TRANSITION_COLOR: Color = Color::srgb(0.5, 1.0, 0.5);
 const GRAVITY_INVERTER_COLOR: Color = Color::srgba(0.1, 0.2, 0.2, 0.5);
+const SPIKES_COLOR: Color = Color::srgb(0.55, 0.55, 0.6);
+const LAVA_COLOR: Color = Color::srgb(1.0, 0.35, 0.05);
+const ELECTRIC_COLOR: Color = Color::srgba(0.4, 0.9, 1.0, 0.8);
 
-pub static LEVELS: LazyLock<LevelMap> = LazyLock::new(|| {
-    let mut levels: HashMap<Level, Box<dyn LevelInfo>> = HashMap::with_capacity(20);    //Increment this as needed to avoid over allocating/reallocations
-    levels.insert(Level::StartingRoom, Box::new(StartingRoomInfo));
-    levels.insert(Level::CweamcatLair, Box::new(CweamcatLairInfo));
-    levels.insert(Level::CweamcatHouse, Box::new(CweamcatHouseInfo));
-    levels.insert(Level::Hell1, Box::new(Hell1Info));
-    levels.insert(Level::Hell2, Box::new(Hell2Info));
-    levels.insert(Level::Hell3, Box::new(Hell3Info));
-    levels.insert(Level::Hell4, Box::new(Hell4Info));
-    levels.insert(Level::CerberLair, Box::new(CerberLairInfo));
-    levels.insert(Level::Spaceship1, Box::new(Spaceship1Info));
-    levels.insert(Level::Spaceship2, Box::new(Spaceship2Info));
-    levels.insert(Level::Spaceship3, Box::new(Spaceship3Info));
-    levels.insert(Level::Spaceship4, Box::new(Spaceship4Info));
-    levels.insert(Level::AquwaLair, Box::new(AquwaLairInfo));
-    levels.insert(Level::FactoryTransition, Box::new(FactoryTransitionInfo));
-    levels.insert(Level::Factory1, Box::new(Factory1Info));
-    levels.insert(Level::Factory2, Box::new(Factory2Info));
-    levels.insert(Level::Factory3, Box::new(Factory3Info));
-    levels.insert(Level::Factory4, Box::new(Factory4Info));
-    levels.insert(Level::FactoryHiddenLevel, Box::new(FactoryHiddenLevelInfo));
-    levels.insert(Level::NeuroLair, Box::new(NeuroLairInfo));
-    LevelMap { levels }
-});
-
+// Layouts are loaded from `assets/levels/<level>.level.ron` or `assets/levels/<level>.tmx` at startup.
+#[derive(Resource)]
 pub struct LevelMap {
-    levels: HashMap<Level, Box<dyn LevelInfo>>,
+    levels: HashMap<Level, Handle<LevelAsset>>,
 }
 
 impl LevelMap {
-    pub fn get_level_info(&self, level_name: &Level) -> &dyn LevelInfo {
+    pub fn get_level_handle(&self, level_name: &Level) -> &Handle<LevelAsset> {
         self.levels
             .get(level_name)
             .unwrap_or_else(|| panic!("Attempted to load level info for None level: {level_name:#?}"))
-            .as_ref()
     }
 }
 
-#[derive(Clone, Copy, Hash, Eq, PartialEq, Debug)]
+pub fn load_levels(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+) {
+    let levels = Level::ALL
+        .into_iter()
+        .map(|level| (level, asset_server.load(level.get_asset_path())))
+        .collect();
+
+    commands.insert_resource(LevelMap { levels });
+}
+
+// Resolved the same way the asset server does, so it doesn't depend on the directory the game was started from.
+pub fn get_asset_file_path(asset_path: &str) -> PathBuf {
+    FileAssetReader::new("assets").root_path().join(asset_path)
+}
+
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Level {
     StartingRoom,
     CweamcatLair,
@@ -102,9 +95,106 @@ pub enum Level {
     NeuroLair,
 }
 
+impl Level {
+    pub const ALL: [Level; 20] = [
+        Level::StartingRoom,
+        Level::CweamcatLair,
+        Level::CweamcatHouse,
+        Level::Hell1,
+        Level::Hell2,
+        Level::Hell3,
+        Level::Hell4,
+        Level::CerberLair,
+        Level::Spaceship1,
+        Level::Spaceship2,
+        Level::Spaceship3,
+        Level::Spaceship4,
+        Level::AquwaLair,
+        Level::FactoryTransition,
+        Level::Factory1,
+        Level::Factory2,
+        Level::Factory3,
+        Level::Factory4,
+        Level::FactoryHiddenLevel,
+        Level::NeuroLair,
+    ];
+
+    pub fn get_file_name(&self) -> &'static str {
+        match self {
+            Level::StartingRoom => "starting_room",
+            Level::CweamcatLair => "cweamcat_lair",
+            Level::CweamcatHouse => "cweamcat_house",
+            Level::Hell1 => "hell_1",
+            Level::Hell2 => "hell_2",
+            Level::Hell3 => "hell_3",
+            Level::Hell4 => "hell_4",
+            Level::CerberLair => "cerber_lair",
+            Level::Spaceship1 => "spaceship_1",
+            Level::Spaceship2 => "spaceship_2",
+            Level::Spaceship3 => "spaceship_3",
+            Level::Spaceship4 => "spaceship_4",
+            Level::AquwaLair => "aquwa_lair",
+            Level::FactoryTransition => "factory_transition",
+            Level::Factory1 => "factory_1",
+            Level::Factory2 => "factory_2",
+            Level::Factory3 => "factory_3",
+            Level::Factory4 => "factory_4",
+            Level::FactoryHiddenLevel => "factory_hidden_level",
+            Level::NeuroLair => "neuro_lair",
+        }
+    }
+
+    // Level names are looked up by the name of the level file.
+    pub fn get_localization_id(&self) -> String {
+        format!("level.{}", self.get_file_name())
+    }
+
+    // Levels authored in Tiled take precedence over the level file of the same name.
+    pub fn get_asset_path(&self) -> String {
+        let tiled_path = format!("levels/{}.tmx", self.get_file_name());
+
+        if get_asset_file_path(&tiled_path).exists() {
+            tiled_path
+        }
+        else {
+            format!("levels/{}.level.ron", self.get_file_name())
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Level::StartingRoom => "Starting Room",
+            Level::CweamcatLair => "Cweamcat Lair",
+            Level::CweamcatHouse => "Cweamcat House",
+            Level::Hell1 => "Hell 1",
+            Level::Hell2 => "Hell 2",
+            Level::Hell3 => "Hell 3",
+            Level::Hell4 => "Hell 4",
+            Level::CerberLair => "Cerber Lair",
+            Level::Spaceship1 => "Spaceship 1",
+            Level::Spaceship2 => "Spaceship 2",
+            Level::Spaceship3 => "Spaceship 3",
+            Level::Spaceship4 => "Spaceship 4",
+            Level::AquwaLair => "Aquwa Lair",
+            Level::FactoryTransition => "Factory Entrance",
+            Level::Factory1 => "Factory 1",
+            Level::Factory2 => "Factory 2",
+            Level::Factory3 => "Factory 3",
+            Level::Factory4 => "Factory 4",
+            Level::FactoryHiddenLevel => "Hidden Factory",
+            Level::NeuroLair => "Neuro Lair",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
 #[derive(Component)]
 pub struct BackgroundComponent;
 
+#[derive(Clone, Copy)]
 pub struct LevelTransitionInfo {
     pub transition_to_index: u32,
     pub transition_to_position: Option<Vec3>
@@ -112,13 +202,14 @@ pub struct LevelTransitionInfo {
 
 #[derive(Component)]
 pub struct LevelLayout {
+    pub level: Level,
     pub floor_layout: Box<[FloorInfo]>,
     pub transition_layout: Option<Box<[TransitionCollider]>>,
     pub npc_layout: Option<Box<[NPC]>>,
     pub door_layout: Option<Box<[DoorCollider]>>,
     pub floor_modifications: Option<Box<[FloorModification]>>,
     pub transition_info: LevelTransitionInfo,
-    pub bgm: Option<&'static str>,
+    pub bgm: Option<String>,
     pub background: FloorAssetType,
 }
 
@@ -163,7 +254,7 @@ pub fn spawn_new_level(
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
     for level_layout in level_layout_query.iter() {
-        match level_layout.bgm {
+        match &level_layout.bgm {
             Some(bgm) => {
                 if bgm_query.is_empty() {
                     let audio_handle = load_asset(&asset_server, format!("ost/{}.mp3", bgm));
@@ -403,7 +494,7 @@ pub fn spawn_new_level(
                         let texture = load_asset(&asset_server, "floor_modifications/Lever1.png");
 
                         commands
-                            .spawn(*time_trial)
+                            .spawn(time_trial.clone())
                             .insert((
                                 Sprite {
                                     image: texture,
@@ -446,12 +537,134 @@ pub fn spawn_new_level(
                                 },
                             ));
                     },
+                    FloorModification::Hazard(hazard) => {
+                        let color = match hazard.hazard_type {
+                            HazardType::Spikes => SPIKES_COLOR,
+                            HazardType::Lava => LAVA_COLOR,
+                            HazardType::Electric => ELECTRIC_COLOR
+                        };
+
+                        commands
+                            .spawn(*hazard)
+                            .insert((
+                                Mesh2d(meshes.add(Rectangle::new(hazard.floor_info.size.x, hazard.floor_info.size.y))),
+                                MeshMaterial2d(materials.add(color)),
+                                Transform::from_translation(hazard.floor_info.position)
+                            ))
+                            .insert(Collider::cuboid(hazard.floor_info.size.x / 2.0, hazard.floor_info.size.y / 2.0))
+                            .insert(Sensor)
+                            .insert(ActiveEvents::COLLISION_EVENTS);
+                    },
+                    FloorModification::Checkpoint(checkpoint) => {
+                        let texture = load_asset(&asset_server, "floor_modifications/Bench.png");
+
+                        commands
+                            .spawn(*checkpoint)
+                            .insert((
+                                Sprite {
+                                    image: texture,
+                                    anchor: bevy::sprite::Anchor::Center,
+                                    custom_size: Some(Vec2::new(checkpoint.floor_info.size.x, checkpoint.floor_info.size.y)),
+                                    ..default()
+                                },
+                                Transform::from_translation(checkpoint.floor_info.position),
+                                Interactable
+                            ))
+                            .insert(Collider::cuboid(checkpoint.floor_info.size.x / 2.0, checkpoint.floor_info.size.y / 2.0))
+                            .insert(Sensor)
+                            .insert(ActiveEvents::COLLISION_EVENTS);
+                    },
+                    FloorModification::MovingPlatform(moving_platform) => {
+                        let tile_handle = match moving_platform.floor_info.floor_asset {
+                            FloorAssetType::Forest => load_asset(&asset_server, "tiles/Forest.png"),
+                            FloorAssetType::CweamcatHouse => load_asset(&asset_server, "tiles/CweamcatHouse.png"),
+                            FloorAssetType::Hell => load_asset(&asset_server, "tiles/Hell.png"),
+                            FloorAssetType::Spaceship => load_asset(&asset_server, "tiles/Spaceship.png"),
+                            FloorAssetType::Factory => load_asset(&asset_server, "tiles/Factory.png")
+                        };
+
+                        let mut moving_platform = moving_platform.clone().sanitized();
+                        moving_platform.is_running = moving_platform.lever_id.is_none();
+
+                        let size = moving_platform.floor_info.size;
+                        let position = moving_platform.floor_info.position;
+
+                        commands
+                            .spawn(moving_platform)
+                            .insert((
+                                RigidBody::KinematicPositionBased,
+                                Transform::from_translation(position),
+                                Sprite {
+                                    image: tile_handle,
+                                    anchor: bevy::sprite::Anchor::Center,
+                                    custom_size: Some(size),
+                                    image_mode: SpriteImageMode::Sliced(TextureSlicer {
+                                        border: BorderRect { left: 0., right: 0., top: 40., bottom: 0. },
+                                        center_scale_mode: SliceScaleMode::Tile { stretch_value: 1. },
+                                        sides_scale_mode: SliceScaleMode::Tile { stretch_value: 1. },
+                                        max_corner_scale: 1.0
+                                    }),
+                                    ..default()
+                                }
+                            ))
+                            .insert(Collider::cuboid(size.x / 2.0, size.y / 2.0))
+                            .insert(Friction::coefficient(0.7))
+                            .insert(ActiveEvents::COLLISION_EVENTS)
+                            .insert(FloorCollider::default());
+                    },
+                    FloorModification::PlatformLever(platform_lever) => {
+                        let texture = load_asset(&asset_server, "floor_modifications/Lever1.png");
+
+                        commands
+                            .spawn(*platform_lever)
+                            .insert((
+                                Sprite {
+                                    image: texture,
+                                    anchor: bevy::sprite::Anchor::Center,
+                                    custom_size: Some(Vec2::new(platform_lever.lever_info.size.x, platform_lever.lever_info.size.y)),
+                                    ..default()
+                                },
+                                Transform::from_translation(platform_lever.lever_info.position),
+                                Interactable
+                            ))
+                            .insert(Collider::cuboid(platform_lever.lever_info.size.x / 2.0, platform_lever.lever_info.size.y / 2.0))
+                            .insert(Sensor)
+                            .insert(ActiveEvents::COLLISION_EVENTS);
+                    },
+                    FloorModification::CrumblingPlatform(crumbling_platform) => {
+                        let texture = load_asset(&asset_server, "floor_modifications/CrumblingPlatform.png");
+                        let size = crumbling_platform.floor_info.size;
+
+                        // The sprite is a child so it can shake and fall without taking the collider along.
+                        commands
+                            .spawn(*crumbling_platform)
+                            .insert((
+                                RigidBody::Fixed,
+                                Transform::from_translation(crumbling_platform.floor_info.position),
+                                Visibility::default(),
+                                Collider::cuboid(size.x / 2.0, size.y / 2.0),
+                                Friction::coefficient(0.7),
+                                ActiveEvents::COLLISION_EVENTS,
+                                FloorCollider::default()
+                            ))
+                            .with_child((
+                                Sprite {
+                                    image: texture,
+                                    anchor: bevy::sprite::Anchor::Center,
+                                    custom_size: Some(size),
+                                    image_mode: SpriteImageMode::Tiled { tile_x: true, tile_y: true, stretch_value: 1. },
+                                    ..default()
+                                },
+                                Transform::default(),
+                                CrumblingPlatformSprite
+                            ));
+                    },
                     FloorModification::Decoration(decoration) => {
                         let decoration_handle = load_asset(&asset_server, format!("decorations/{}.png", decoration.asset));
 
                         commands
                             .spawn((
-                                *decoration,
+                                decoration.clone(),
                                 Transform::from_translation(decoration.position),
                                 Sensor,
                                 Sprite {
@@ -538,17 +751,181 @@ pub fn manually_transition_to_level(
     transition_state.set(TransitionState::Started);
 }
 
+// Rebuilds the current level when its level file changes on disk, without moving the Cweampuff.
+// Only fires when the game is built with the `hot_reload` feature, which enables asset file watching.
+pub fn level_hot_reload(
+    mut level_asset_events: EventReader<AssetEvent<LevelAsset>>,
+    mut commands: Commands,
+    level_map: Res<LevelMap>,
+    level_layout_query: Query<(Entity, &LevelLayout)>,
+    cweampuff: Single<(&Cweampuff, &Transform, &GravityScale)>,
+) {
+    let modified_levels: Vec<AssetId<LevelAsset>> = level_asset_events.read()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { id } => Some(*id),
+            _ => None
+        })
+        .collect();
+
+    let Ok((layout_entity, level_layout)) = level_layout_query.single() else {
+        return;
+    };
+
+    let level = level_layout.level;
+
+    if !modified_levels.contains(&level_map.get_level_handle(&level).id()) {
+        return;
+    }
+
+    info!("Reloading level {:?}", level);
+
+    let (cweampuff, cweampuff_transform, cweampuff_gravity) = *cweampuff;
+
+    reload_level(&mut commands, layout_entity, level, cweampuff, cweampuff_transform, cweampuff_gravity.0);
+}
+
+// Despawns and respawns the level in place, leaving the Cweampuff where it is.
+pub fn reload_level(
+    commands: &mut Commands,
+    layout_entity: Entity,
+    level: Level,
+    cweampuff: &Cweampuff,
+    cweampuff_transform: &Transform,
+    gravity_scale: f32
+) {
+    commands.entity(layout_entity).despawn();
+
+    let transition_info = LevelTransitionInfo { transition_to_index: 0, transition_to_position: Some(cweampuff_transform.translation) };
+
+    spawn_level(commands, level, cweampuff, transition_info);
+
+    commands.run_system_cached(despawn_current_level);
+    commands.run_system_cached(spawn_new_level);
+
+    commands.queue(move |world: &mut World| {
+        // The level is swapped in place, so there is no transition to finish and the Cweampuff keeps falling the way it was.
+        world.resource_mut::<NextState<TransitionState>>().reset();
+
+        let mut cweampuff_query = world.query_filtered::<&mut GravityScale, With<Cweampuff>>();
+
+        for mut gravity in cweampuff_query.iter_mut(world) {
+            gravity.0 = gravity_scale;
+        }
+    });
+}
+
+// A level that was transitioned to before its level or dialogue files finished loading.
+// The screen stays faded in until it's built, since `spawn_new_level` waits for the layout.
+#[derive(Resource)]
+pub struct PendingLevel {
+    level: Level,
+    cweampuff: Cweampuff,
+    transition_info: LevelTransitionInfo
+}
+
 fn spawn_level(commands: &mut Commands, level: Level, cweampuff: &Cweampuff, transition_info: LevelTransitionInfo) {
-    let layout_info = LEVELS.get_level_info(&level);
-
-    commands.spawn(LevelLayout {
-        floor_layout: layout_info.get_floor_info(cweampuff),
-        transition_layout: layout_info.get_transitions_info(cweampuff),
-        npc_layout: layout_info.get_npcs(cweampuff),
-        door_layout: layout_info.get_doors(cweampuff),
-        floor_modifications: layout_info.get_floor_modifications(cweampuff),
-        transition_info,
-        bgm: layout_info.get_bgm(),
-        background: layout_info.get_background()
+    let cweampuff = *cweampuff;
+
+    // Level files live in the asset system, so the layout is built once the command gets access to the world.
+    commands.queue(move |world: &mut World| {
+        if !try_spawn_level_layout(world, level, &cweampuff, transition_info) {
+            world.insert_resource(PendingLevel { level, cweampuff, transition_info });
+        }
     });
-}
\ No newline at end of file
+}
+
+pub fn spawn_pending_level(world: &mut World) {
+    let Some(PendingLevel { level, cweampuff, transition_info }) = world.remove_resource::<PendingLevel>() else {
+        return;
+    };
+
+    let level_handle = world.resource::<LevelMap>().get_level_handle(&level).clone();
+
+    // There is nothing to wait for, so the game goes back to the main menu instead of leaving the player in an empty world.
+    if world.resource::<AssetServer>().load_state(&level_handle).is_failed() {
+        error!("Level file for {:?} failed to load", level);
+
+        world.resource_mut::<NextState<TransitionState>>().set(TransitionState::Finished);
+        world.resource_mut::<NextState<AppState>>().set(AppState::MainMenu);
+        world.resource_mut::<NextState<FadeState>>().set(FadeState::FadeOut);
+
+        return;
+    }
+
+    if !try_spawn_level_layout(world, level, &cweampuff, transition_info) {
+        world.insert_resource(PendingLevel { level, cweampuff, transition_info });
+    }
+}
+
+// Returns false while the level file or any dialogue file is still loading.
+fn try_spawn_level_layout(world: &mut World, level: Level, cweampuff: &Cweampuff, transition_info: LevelTransitionInfo) -> bool {
+    let cweampuff = *cweampuff;
+    let level_handle = world.resource::<LevelMap>().get_level_handle(&level).clone();
+    let asset_server = world.resource::<AssetServer>();
+
+    let are_dialogues_loading = world.resource::<DialogueMap>().get_dialogue_handles()
+        .any(|handle| asset_server.load_state(handle).is_loading());
+
+    if !asset_server.is_loaded_with_dependencies(&level_handle) || are_dialogues_loading {
+        return false;
+    }
+
+    let Some(level_asset) = world.resource::<Assets<LevelAsset>>().get(&level_handle) else {
+        return false;
+    };
+
+    let level_layout = LevelLayout {
+        level,
+        floor_layout: level_asset.get_sections(&cweampuff).flat_map(|section| section.floors.iter().copied()).collect(),
+        transition_layout: get_section_entries(level_asset.get_sections(&cweampuff).flat_map(|section| section.transitions.iter().copied())),
+        npc_layout: get_section_entries(level_asset.get_sections(&cweampuff)
+            .flat_map(|section| section.npcs.iter())
+            .filter(|npc_placement| npc_placement.progression.contains(cweampuff.progression))
+            .map(|npc_placement| NPC::new(npc_placement.name.clone(), npc_placement.floor_info)))
+            .map(|npcs| with_conversations(world, npcs, level, &cweampuff)),
+        door_layout: get_section_entries(level_asset.get_sections(&cweampuff).flat_map(|section| section.doors.iter().copied())),
+        floor_modifications: get_section_entries(level_asset.get_sections(&cweampuff).flat_map(|section| section.floor_modifications.iter().cloned())),
+        transition_info,
+        bgm: level_asset.bgm.clone(),
+        background: level_asset.background
+    };
+
+    world.spawn(level_layout);
+
+    true
+}
+
+// NPCs are placed by the level file, what they say and do afterwards comes from their dialogue file.
+fn with_conversations(world: &World, npcs: Box<[NPC]>, level: Level, cweampuff: &Cweampuff) -> Box<[NPC]> {
+    let dialogue_map = world.resource::<DialogueMap>();
+    let dialogue_assets = world.resource::<Assets<DialogueAsset>>();
+    let dialogue_flags = world.resource::<DialogueFlags>();
+
+    npcs.into_vec()
+        .into_iter()
+        .map(|mut npc| {
+            let Some(dialogue) = dialogue_map.get_dialogue_handle(&npc.name).and_then(|handle| dialogue_assets.get(handle)) else {
+                error!("Dialogue file for {} is not loaded", npc.name);
+                return npc;
+            };
+
+            if let Some(conversation) = dialogue.get_conversation(level, cweampuff.progression, dialogue_flags) {
+                npc.conversation = conversation.entries.clone();
+                npc.after_conversation = conversation.after_conversation.clone();
+            }
+
+            npc
+        })
+        .collect()
+}
+
+fn get_section_entries<T>(entries: impl Iterator<Item = T>) -> Option<Box<[T]>> {
+    let entries: Box<[T]> = entries.collect();
+
+    if entries.is_empty() {
+        None
+    }
+    else {
+        Some(entries)
+    }
+}
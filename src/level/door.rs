@@ -3,6 +3,7 @@ use bevy_rapier2d::prelude::CollisionEvent;
 
 use crate::{audio_settings::AudioSettings, interactable::{interaction_state::InteractionState, Interactable}, npc::NPC, Cweampuff};
 use crate::asset_loader::load_asset;
+use crate::controls::{ActionInput, GameAction};
 use crate::movement::check_entities;
 use super::{level_layout::{DoorCollider, DoorType}, manually_transition_to_level, transition_states::TransitionState, LevelLayout};
 
@@ -38,7 +39,7 @@ pub fn interactable_door_collision_reader(
 }
 
 pub fn door_start_interaction_input_reader(
-    keyboard_input: Res<ButtonInput<KeyCode>>, 
+    action_input: ActionInput,
     doors: Query<&DoorCollider, (With<Interactable>, Without<NPC>)>,
     cweampuff: Single<&Cweampuff, With<Cweampuff>>,
     mut commands: Commands,
@@ -47,7 +48,7 @@ pub fn door_start_interaction_input_reader(
     asset_server: Res<AssetServer>,
     audio_settings: Res<AudioSettings>,
 ) {
-    if !keyboard_input.just_pressed(KeyCode::KeyE) {
+    if !action_input.just_pressed(GameAction::Interact) {
         return;
     }
 
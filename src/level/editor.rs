@@ -0,0 +1,975 @@
+use bevy::{ecs::system::SystemParam, input::mouse::AccumulatedMouseScroll, prelude::*, window::PrimaryWindow};
+use bevy_rapier2d::prelude::*;
+use serde::Serialize;
+
+use crate::{app_states::AppState, fade_in_fade_out::FadeInFadeOutNode, main_menu::DEFAULT_FONT, npc::NPC_NAMES, save::write_user_data_file, Cweampuff};
+
+use super::{
+    get_asset_file_path,
+    level_asset::{LevelAsset, LevelSection},
+    level_layout::{Checkpoint, CrumbleState, CrumblingPlatform, EntityInfo, FloorInfo, FloorModification, GravityInverter, Hazard, HazardType, IllusoryWall, JumpPad, MovingPlatform, NpcPlacement, PlatformPathMode},
+    progression::Progression,
+    reload_level, Level, LevelLayout, LevelMap,
+};
+
+const GRID_SIZE: f32 = 50.;
+const HANDLE_RADIUS: f32 = 15.;
+const CAMERA_PAN_SPEED: f32 = 1500.;
+const MIN_ZOOM: f32 = 0.5;
+const MAX_ZOOM: f32 = 6.;
+
+const GRID_COLOR: Color = Color::srgba(1., 1., 1., 0.08);
+const FLOOR_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const TRANSITION_COLOR: Color = Color::srgb(0.5, 1.0, 0.5);
+const DOOR_COLOR: Color = Color::srgb(0.4, 0.6, 1.0);
+const FLOOR_MODIFICATION_COLOR: Color = Color::srgb(1.0, 0.7, 0.2);
+const NPC_COLOR: Color = Color::srgb(1.0, 0.5, 0.9);
+const SELECTED_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+
+// The editor runs on top of the level being played, so leaving it doesn't count as leaving the game.
+#[derive(SubStates, Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[source(AppState = AppState::InGame)]
+pub enum EditorMode {
+    #[default]
+    Off,
+    On
+}
+
+#[derive(Component)]
+pub struct EditorComponent;
+
+type EditorNodeFilter = (With<Node>, With<EditorComponent>, Without<Camera2d>, Without<FadeInFadeOutNode>);
+
+#[derive(Component)]
+pub struct EditorStatusText;
+
+// Sent whenever the edited level data changes, so the level entities can be rebuilt from it.
+#[derive(Event)]
+pub struct EditorLevelChangedEvent;
+
+// Points at an entry of the level file by section index and index within that section.
+#[derive(Clone, Copy, PartialEq)]
+enum EditorItem {
+    Floor(usize, usize),
+    Transition(usize, usize),
+    Door(usize, usize),
+    FloorModification(usize, usize),
+    Npc(usize, usize),
+}
+
+impl EditorItem {
+    fn get_section(&self) -> usize {
+        match self {
+            EditorItem::Floor(section, _) |
+            EditorItem::Transition(section, _) |
+            EditorItem::Door(section, _) |
+            EditorItem::FloorModification(section, _) |
+            EditorItem::Npc(section, _) => *section
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum DragMode {
+    // Offset from the cursor to the top left corner of the dragged item.
+    Move(Vec2),
+    Resize,
+    // Index of the dragged waypoint of the selected moving platform.
+    Waypoint(usize)
+}
+
+#[derive(Resource)]
+pub struct EditorState {
+    level: Level,
+    selected: Option<EditorItem>,
+    drag: Option<DragMode>,
+    snap_to_grid: bool,
+    has_unsaved_changes: bool,
+    has_unapplied_changes: bool
+}
+
+impl EditorState {
+    fn snap(&self, position: Vec2) -> Vec2 {
+        if self.snap_to_grid {
+            (position / GRID_SIZE).round() * GRID_SIZE
+        }
+        else {
+            position
+        }
+    }
+}
+
+#[derive(SystemParam)]
+pub struct EditorCursor<'w> {
+    window: Single<'w, &'static Window, With<PrimaryWindow>>,
+    camera: Single<'w, (&'static Camera, &'static GlobalTransform, &'static Projection), With<Camera2d>>,
+}
+
+impl EditorCursor<'_> {
+    fn get_world_position(&self) -> Option<Vec2> {
+        let (camera, camera_transform, _) = *self.camera;
+
+        self.window.cursor_position().and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+    }
+
+    fn get_zoom(&self) -> f32 {
+        get_zoom(self.camera.2)
+    }
+}
+
+// The level file being edited, the level entities are rebuilt from it after every change.
+#[derive(SystemParam)]
+pub struct EditedLevel<'w> {
+    level_map: Res<'w, LevelMap>,
+    level_assets: ResMut<'w, Assets<LevelAsset>>,
+    level_changed_writer: EventWriter<'w, EditorLevelChangedEvent>,
+}
+
+impl EditedLevel<'_> {
+    fn get(&self, level: Level) -> Option<&LevelAsset> {
+        self.level_assets.get(self.level_map.get_level_handle(&level))
+    }
+
+    fn get_mut(&mut self, level: Level) -> Option<&mut LevelAsset> {
+        self.level_assets.get_mut(self.level_map.get_level_handle(&level))
+    }
+
+    fn send_changed_event(&mut self) {
+        self.level_changed_writer.write(EditorLevelChangedEvent);
+    }
+}
+
+// The editor is a development tool only, release builds never open it.
+pub fn editor_toggle_input_reader(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    editor_mode: Res<State<EditorMode>>,
+    mut next_editor_mode: ResMut<NextState<EditorMode>>,
+) {
+    if !cfg!(debug_assertions) || !keyboard_input.just_pressed(KeyCode::F1) {
+        return;
+    }
+
+    match editor_mode.get() {
+        EditorMode::Off => next_editor_mode.set(EditorMode::On),
+        EditorMode::On => next_editor_mode.set(EditorMode::Off),
+    }
+}
+
+pub fn spawn_editor(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    level_layout: Single<&LevelLayout>,
+    mut rapier_configuration: Query<&mut RapierConfiguration>,
+) {
+    for mut configuration in rapier_configuration.iter_mut() {
+        configuration.physics_pipeline_active = false;
+    }
+
+    commands.insert_resource(EditorState {
+        level: level_layout.level,
+        selected: None,
+        drag: None,
+        snap_to_grid: true,
+        has_unsaved_changes: false,
+        has_unapplied_changes: false
+    });
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            top: Val::Percent(1.),
+            left: Val::Percent(1.),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        EditorComponent
+    )).with_child((
+        Text::new(""),
+        EditorStatusText,
+        TextShadow {
+            offset: Vec2::splat(2.),
+            color: Color::linear_rgba(0., 0., 0., 1.),
+        },
+        TextFont {
+            font: asset_server.load(DEFAULT_FONT),
+            font_size: 26.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.9, 0.9, 0.9)),
+    ));
+}
+
+pub fn despawn_editor(
+    mut commands: Commands,
+    query: Query<Entity, EditorNodeFilter>,
+    mut rapier_configuration: Query<&mut RapierConfiguration>,
+    mut projection: Single<&mut Projection, With<Camera2d>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    for mut configuration in rapier_configuration.iter_mut() {
+        configuration.physics_pipeline_active = true;
+    }
+
+    if let Projection::Orthographic(orthographic) = &mut **projection {
+        orthographic.scale = 1.;
+    }
+
+    commands.remove_resource::<EditorState>();
+}
+
+pub fn editor_camera_movement(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    mut camera: Single<(&mut Transform, &mut Projection), With<Camera2d>>,
+    time: Res<Time>,
+) {
+    let (camera_transform, projection) = &mut *camera;
+
+    let Projection::Orthographic(orthographic) = &mut **projection else {
+        return;
+    };
+
+    if mouse_scroll.delta.y != 0. {
+        orthographic.scale = (orthographic.scale * (1. - mouse_scroll.delta.y.signum() * 0.1)).clamp(MIN_ZOOM, MAX_ZOOM);
+    }
+
+    let mut direction = Vec2::ZERO;
+
+    if keyboard_input.pressed(KeyCode::ArrowLeft) {
+        direction.x -= 1.;
+    }
+    if keyboard_input.pressed(KeyCode::ArrowRight) {
+        direction.x += 1.;
+    }
+    if keyboard_input.pressed(KeyCode::ArrowUp) {
+        direction.y += 1.;
+    }
+    if keyboard_input.pressed(KeyCode::ArrowDown) {
+        direction.y -= 1.;
+    }
+
+    let offset = direction.normalize_or_zero() * CAMERA_PAN_SPEED * orthographic.scale * time.delta_secs();
+
+    camera_transform.translation += offset.extend(0.);
+}
+
+pub fn editor_mouse_input(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    cursor: EditorCursor,
+    cweampuff: Single<&Cweampuff>,
+    mut edited_level: EditedLevel,
+    mut editor_state: ResMut<EditorState>,
+) {
+    let Some(cursor_position) = cursor.get_world_position() else {
+        return;
+    };
+
+    let Some(level_asset) = edited_level.get(editor_state.level) else {
+        return;
+    };
+
+    if mouse_input.just_pressed(MouseButton::Left) {
+        // Grabbing the corner of the selected item resizes it instead of selecting whatever is underneath.
+        if let Some(info) = editor_state.selected.and_then(|item| get_item_info(level_asset, item)) {
+            if cursor_position.distance(get_bottom_right(&info)) <= HANDLE_RADIUS * cursor.get_zoom() {
+                editor_state.drag = Some(DragMode::Resize);
+                return;
+            }
+        }
+
+        if let Some(index) = get_hovered_waypoint(level_asset, editor_state.selected, cursor_position, HANDLE_RADIUS * cursor.get_zoom()) {
+            editor_state.drag = Some(DragMode::Waypoint(index));
+            return;
+        }
+
+        // Smaller items win, so jump pads and doors can still be picked on top of the floors they sit on.
+        let hovered_item = get_items(level_asset, cweampuff.progression)
+            .into_iter()
+            .filter_map(|item| get_item_info(level_asset, item).map(|info| (item, info)))
+            .filter(|(_, info)| Rect::from_center_size(info.position.truncate(), info.size).contains(cursor_position))
+            .min_by(|(_, a), (_, b)| (a.size.x * a.size.y).total_cmp(&(b.size.x * b.size.y)));
+
+        editor_state.selected = hovered_item.map(|(item, _)| item);
+        editor_state.drag = hovered_item.map(|(_, info)| DragMode::Move(get_top_left(&info) - cursor_position));
+    }
+    else if mouse_input.pressed(MouseButton::Left) {
+        let (Some(item), Some(drag)) = (editor_state.selected, editor_state.drag) else {
+            return;
+        };
+
+        let Some(info) = get_item_info(level_asset, item) else {
+            return;
+        };
+
+        let top_left = get_top_left(&info);
+
+        let (top_left, size) = match drag {
+            DragMode::Move(offset) => (editor_state.snap(cursor_position + offset), info.size),
+            DragMode::Resize => {
+                let bottom_right = editor_state.snap(cursor_position);
+
+                (top_left, Vec2::new((bottom_right.x - top_left.x).max(GRID_SIZE), (top_left.y - bottom_right.y).max(GRID_SIZE)))
+            },
+            // Waypoints are offsets from the platform, snapping those keeps the platform on the grid along its whole path.
+            DragMode::Waypoint(index) => {
+                let waypoint = editor_state.snap(cursor_position - info.position.truncate());
+
+                let Some(moving_platform) = edited_level.get_mut(editor_state.level).and_then(|level_asset| get_moving_platform_mut(level_asset, item)) else {
+                    return;
+                };
+
+                if let Some(old_waypoint) = moving_platform.waypoints.get_mut(index).filter(|old_waypoint| **old_waypoint != waypoint) {
+                    *old_waypoint = waypoint;
+
+                    editor_state.has_unsaved_changes = true;
+                    editor_state.has_unapplied_changes = true;
+                }
+
+                return;
+            }
+        };
+
+        let position = Vec2::new(top_left.x + size.x / 2., top_left.y - size.y / 2.).extend(info.position.z);
+
+        if position == info.position && size == info.size {
+            return;
+        }
+
+        if let Some(level_asset) = edited_level.get_mut(editor_state.level) {
+            set_item_info(level_asset, item, EntityInfo { position, size });
+
+            editor_state.has_unsaved_changes = true;
+            editor_state.has_unapplied_changes = true;
+        }
+    }
+    else if mouse_input.just_released(MouseButton::Left) {
+        editor_state.drag = None;
+
+        // Rebuilding the level on every dragged pixel is too slow, so it waits until the item is let go.
+        if editor_state.has_unapplied_changes {
+            editor_state.has_unapplied_changes = false;
+            edited_level.send_changed_event();
+        }
+    }
+    // Right clicking a waypoint of the selected moving platform removes it, anywhere else adds one there.
+    else if mouse_input.just_pressed(MouseButton::Right) {
+        let Some(item) = editor_state.selected else {
+            return;
+        };
+
+        let hovered_waypoint = get_hovered_waypoint(level_asset, Some(item), cursor_position, HANDLE_RADIUS * cursor.get_zoom());
+
+        let Some(moving_platform) = edited_level.get_mut(editor_state.level).and_then(|level_asset| get_moving_platform_mut(level_asset, item)) else {
+            return;
+        };
+
+        match hovered_waypoint {
+            // A platform with no waypoints left would have nowhere to go.
+            Some(_) if moving_platform.waypoints.len() == 1 => return,
+            Some(index) => {
+                moving_platform.waypoints.remove(index);
+            },
+            None => {
+                let waypoint = editor_state.snap(cursor_position - moving_platform.floor_info.position.truncate());
+
+                moving_platform.waypoints.push(waypoint);
+            }
+        }
+
+        editor_state.has_unsaved_changes = true;
+        edited_level.send_changed_event();
+    }
+}
+
+pub fn editor_keyboard_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    cursor: EditorCursor,
+    cweampuff: Single<&Cweampuff>,
+    mut edited_level: EditedLevel,
+    mut editor_state: ResMut<EditorState>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyG) {
+        editor_state.snap_to_grid = !editor_state.snap_to_grid;
+    }
+
+    if keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) && keyboard_input.just_pressed(KeyCode::KeyS) {
+        if let Some(level_asset) = edited_level.get(editor_state.level) {
+            if save_level_file(editor_state.level, level_asset) {
+                editor_state.has_unsaved_changes = false;
+            }
+        }
+
+        return;
+    }
+
+    if keyboard_input.any_just_pressed([KeyCode::Delete, KeyCode::Backspace]) {
+        let Some(item) = editor_state.selected.take() else {
+            return;
+        };
+
+        if let Some(level_asset) = edited_level.get_mut(editor_state.level) {
+            remove_item(level_asset, item);
+
+            editor_state.drag = None;
+            editor_state.has_unsaved_changes = true;
+            edited_level.send_changed_event();
+        }
+
+        return;
+    }
+
+    // Gives the selected NPC the next name that has a dialogue file.
+    if keyboard_input.just_pressed(KeyCode::KeyN) {
+        let Some(EditorItem::Npc(section_index, index)) = editor_state.selected else {
+            return;
+        };
+
+        let Some(npc) = edited_level.get_mut(editor_state.level).and_then(|level_asset| level_asset.sections.get_mut(section_index)?.npcs.get_mut(index)) else {
+            return;
+        };
+
+        let name_index = NPC_NAMES.iter().position(|name| *name == npc.name).map_or(0, |name_index| (name_index + 1) % NPC_NAMES.len());
+        npc.name = NPC_NAMES[name_index].to_string();
+
+        editor_state.has_unsaved_changes = true;
+        edited_level.send_changed_event();
+
+        return;
+    }
+
+    let Some(cursor_position) = cursor.get_world_position() else {
+        return;
+    };
+
+    let cursor_position = editor_state.snap(cursor_position);
+
+    let new_item = if keyboard_input.just_pressed(KeyCode::Digit1) {
+        NewItem::Floor
+    }
+    else if keyboard_input.just_pressed(KeyCode::Digit2) {
+        NewItem::JumpPad
+    }
+    else if keyboard_input.just_pressed(KeyCode::Digit3) {
+        NewItem::GravityInverter
+    }
+    else if keyboard_input.just_pressed(KeyCode::Digit4) {
+        NewItem::IllusoryWall
+    }
+    else if keyboard_input.just_pressed(KeyCode::Digit5) {
+        NewItem::Hazard
+    }
+    else if keyboard_input.just_pressed(KeyCode::Digit6) {
+        NewItem::Checkpoint
+    }
+    else if keyboard_input.just_pressed(KeyCode::Digit7) {
+        NewItem::MovingPlatform
+    }
+    else if keyboard_input.just_pressed(KeyCode::Digit8) {
+        NewItem::CrumblingPlatform
+    }
+    else if keyboard_input.just_pressed(KeyCode::Digit9) {
+        NewItem::Npc
+    }
+    else {
+        return;
+    };
+
+    let Some(level_asset) = edited_level.get_mut(editor_state.level) else {
+        return;
+    };
+
+    // New items go next to the selected one, otherwise into a section that is always present.
+    let section_index = editor_state.selected
+        .map(|item| item.get_section())
+        .or_else(|| level_asset.sections.iter().position(|section| section.progression.is_always()))
+        .unwrap_or_else(|| {
+            level_asset.sections.push(LevelSection::default());
+            level_asset.sections.len() - 1
+        });
+
+    let background = level_asset.background;
+    let section = &mut level_asset.sections[section_index];
+
+    let item = match new_item {
+        NewItem::Floor => {
+            section.floors.push(FloorInfo { position: cursor_position.extend(1.), size: Vec2::new(300., 100.), breakable_wall: None, floor_asset: background });
+
+            EditorItem::Floor(section_index, section.floors.len() - 1)
+        },
+        NewItem::JumpPad => {
+            section.floor_modifications.push(FloorModification::JumpPad(JumpPad { floor_info: EntityInfo { position: cursor_position.extend(0.), size: Vec2::new(200., 200.) } }));
+
+            EditorItem::FloorModification(section_index, section.floor_modifications.len() - 1)
+        },
+        NewItem::GravityInverter => {
+            section.floor_modifications.push(FloorModification::GravityInverter(GravityInverter { floor_info: EntityInfo { position: cursor_position.extend(0.), size: Vec2::new(300., 300.) } }));
+
+            EditorItem::FloorModification(section_index, section.floor_modifications.len() - 1)
+        },
+        NewItem::IllusoryWall => {
+            section.floor_modifications.push(FloorModification::IllusoryWall(IllusoryWall { position: cursor_position.extend(4.), size: Vec2::new(300., 200.), floor_asset: background }));
+
+            EditorItem::FloorModification(section_index, section.floor_modifications.len() - 1)
+        },
+        NewItem::Hazard => {
+            section.floor_modifications.push(FloorModification::Hazard(Hazard { floor_info: EntityInfo { position: cursor_position.extend(1.), size: Vec2::new(300., 50.) }, hazard_type: HazardType::from_background(background), damage: None }));
+
+            EditorItem::FloorModification(section_index, section.floor_modifications.len() - 1)
+        },
+        NewItem::Checkpoint => {
+            section.floor_modifications.push(FloorModification::Checkpoint(Checkpoint { floor_info: EntityInfo { position: cursor_position.extend(0.), size: Vec2::new(160., 100.) }, is_active: false }));
+
+            EditorItem::FloorModification(section_index, section.floor_modifications.len() - 1)
+        },
+        // New platforms go back and forth to the right until their waypoints are changed with the right mouse button.
+        NewItem::MovingPlatform => {
+            section.floor_modifications.push(FloorModification::MovingPlatform(MovingPlatform {
+                floor_info: FloorInfo { position: cursor_position.extend(1.), size: Vec2::new(300., 100.), breakable_wall: None, floor_asset: background },
+                waypoints: vec![Vec2::new(600., 0.)],
+                speed: 200.,
+                path_mode: PlatformPathMode::PingPong,
+                lever_id: None,
+                is_running: false,
+                next_waypoint: 0,
+                is_reversed: false
+            }));
+
+            EditorItem::FloorModification(section_index, section.floor_modifications.len() - 1)
+        },
+        NewItem::CrumblingPlatform => {
+            section.floor_modifications.push(FloorModification::CrumblingPlatform(CrumblingPlatform {
+                floor_info: FloorInfo { position: cursor_position.extend(1.), size: Vec2::new(300., 100.), breakable_wall: None, floor_asset: background },
+                crumble_secs: 0.8,
+                respawn_secs: 3.,
+                state: CrumbleState::Solid,
+                time_passed: 0.
+            }));
+
+            EditorItem::FloorModification(section_index, section.floor_modifications.len() - 1)
+        },
+        NewItem::Npc => {
+            section.npcs.push(NpcPlacement { name: NPC_NAMES[0].to_string(), floor_info: EntityInfo { position: cursor_position.extend(2.), size: Vec2::new(200., 100.) }, progression: default() });
+
+            EditorItem::Npc(section_index, section.npcs.len() - 1)
+        },
+    };
+
+    // Items added to a section that doesn't match the current progression wouldn't show up.
+    if level_asset.sections[section_index].progression.contains(cweampuff.progression) {
+        editor_state.selected = Some(item);
+    }
+
+    editor_state.drag = None;
+    editor_state.has_unsaved_changes = true;
+    edited_level.send_changed_event();
+}
+
+enum NewItem {
+    Floor,
+    JumpPad,
+    GravityInverter,
+    IllusoryWall,
+    Hazard,
+    Checkpoint,
+    MovingPlatform,
+    CrumblingPlatform,
+    Npc
+}
+
+pub fn editor_level_changed_event_reader(
+    mut level_changed_events: EventReader<EditorLevelChangedEvent>,
+    mut commands: Commands,
+    level_layout: Single<(Entity, &LevelLayout)>,
+    cweampuff: Single<(&Cweampuff, &Transform, &GravityScale), Without<Camera2d>>,
+    camera: Single<&Transform, With<Camera2d>>,
+) {
+    if level_changed_events.is_empty() {
+        return;
+    }
+
+    level_changed_events.clear();
+
+    let (layout_entity, level_layout) = *level_layout;
+    let (cweampuff, cweampuff_transform, cweampuff_gravity) = *cweampuff;
+
+    reload_level(&mut commands, layout_entity, level_layout.level, cweampuff, cweampuff_transform, cweampuff_gravity.0);
+
+    // Spawning the level snaps the camera back to the Cweampuff, which would lose the spot being edited.
+    let camera_translation = camera.translation;
+
+    commands.queue(move |world: &mut World| {
+        let mut camera_query = world.query_filtered::<&mut Transform, With<Camera2d>>();
+
+        for mut camera_transform in camera_query.iter_mut(world) {
+            camera_transform.translation = camera_translation;
+        }
+    });
+}
+
+pub fn draw_editor_gizmos(
+    mut gizmos: Gizmos,
+    editor_state: Res<EditorState>,
+    level_map: Res<LevelMap>,
+    level_assets: Res<Assets<LevelAsset>>,
+    cweampuff: Single<&Cweampuff>,
+    camera: Single<(&Transform, &Projection), With<Camera2d>>,
+) {
+    let (camera_transform, projection) = *camera;
+    let zoom = get_zoom(projection);
+
+    if editor_state.snap_to_grid {
+        let grid_center = (camera_transform.translation.truncate() / GRID_SIZE).round() * GRID_SIZE;
+        let cell_count = (Vec2::new(1920., 1080.) * zoom / GRID_SIZE).as_uvec2() / 2 * 2 + UVec2::splat(2);
+
+        gizmos.grid_2d(Isometry2d::from_translation(grid_center), cell_count, Vec2::splat(GRID_SIZE), GRID_COLOR);
+    }
+
+    let Some(level_asset) = level_assets.get(level_map.get_level_handle(&editor_state.level)) else {
+        return;
+    };
+
+    for item in get_items(level_asset, cweampuff.progression) {
+        let Some(info) = get_item_info(level_asset, item) else {
+            continue;
+        };
+
+        let color = if editor_state.selected == Some(item) {
+            gizmos.circle_2d(Isometry2d::from_translation(get_bottom_right(&info)), HANDLE_RADIUS * zoom, SELECTED_COLOR);
+
+            SELECTED_COLOR
+        }
+        else {
+            match item {
+                EditorItem::Floor(..) => FLOOR_COLOR,
+                EditorItem::Transition(..) => TRANSITION_COLOR,
+                EditorItem::Door(..) => DOOR_COLOR,
+                EditorItem::FloorModification(..) => FLOOR_MODIFICATION_COLOR,
+                EditorItem::Npc(..) => NPC_COLOR,
+            }
+        };
+
+        gizmos.rect_2d(Isometry2d::from_translation(info.position.truncate()), info.size, color);
+
+        if let Some(FloorModification::MovingPlatform(moving_platform)) = get_floor_modification(level_asset, item) {
+            let path = (0..=moving_platform.waypoints.len()).map(|index| moving_platform.get_path_point(index).truncate());
+
+            let path: Vec<Vec2> = match moving_platform.path_mode {
+                PlatformPathMode::PingPong => path.collect(),
+                PlatformPathMode::Loop => path.chain([info.position.truncate()]).collect()
+            };
+
+            gizmos.linestrip_2d(path, color);
+
+            if editor_state.selected == Some(item) {
+                for index in 1..=moving_platform.waypoints.len() {
+                    gizmos.circle_2d(Isometry2d::from_translation(moving_platform.get_path_point(index).truncate()), HANDLE_RADIUS * zoom, SELECTED_COLOR);
+                }
+            }
+        }
+    }
+}
+
+pub fn update_editor_status_text(
+    editor_state: Res<EditorState>,
+    mut status_text: Single<&mut Text, With<EditorStatusText>>,
+) {
+    if !editor_state.is_changed() {
+        return;
+    }
+
+    let unsaved_marker = if editor_state.has_unsaved_changes { " *" } else { "" };
+    let grid_snap = if editor_state.snap_to_grid { "on" } else { "off" };
+
+    status_text.0 = format!(
+        "Editing {}{}\nLeft mouse: select, move, drag the corner or a waypoint | Right mouse: add or remove a waypoint of the selected moving platform | Arrows/wheel: pan and zoom\n1: floor | 2: jump pad | 3: gravity inverter | 4: illusory wall | 5: hazard | 6: checkpoint | 7: moving platform | 8: crumbling platform | 9: NPC | N: next NPC name | Delete: remove\nG: grid snap ({}) | Ctrl+S: save | F1: back to the game",
+        editor_state.level, unsaved_marker, grid_snap
+    );
+}
+
+fn get_items(level_asset: &LevelAsset, progression: Progression) -> Vec<EditorItem> {
+    let mut items = Vec::new();
+
+    for (section_index, section) in level_asset.sections.iter().enumerate() {
+        if !section.progression.contains(progression) {
+            continue;
+        }
+
+        items.extend((0..section.floors.len()).map(|index| EditorItem::Floor(section_index, index)));
+        items.extend((0..section.transitions.len()).map(|index| EditorItem::Transition(section_index, index)));
+        items.extend((0..section.doors.len()).map(|index| EditorItem::Door(section_index, index)));
+        items.extend((0..section.floor_modifications.len()).map(|index| EditorItem::FloorModification(section_index, index)));
+        items.extend(section.npcs.iter().enumerate().filter(|(_, npc)| npc.progression.contains(progression)).map(|(index, _)| EditorItem::Npc(section_index, index)));
+    }
+
+    items
+}
+
+fn get_floor_modification(level_asset: &LevelAsset, item: EditorItem) -> Option<&FloorModification> {
+    match item {
+        EditorItem::FloorModification(section_index, index) => level_asset.sections.get(section_index)?.floor_modifications.get(index),
+        _ => None
+    }
+}
+
+fn get_moving_platform_mut(level_asset: &mut LevelAsset, item: EditorItem) -> Option<&mut MovingPlatform> {
+    match item {
+        EditorItem::FloorModification(section_index, index) => match level_asset.sections.get_mut(section_index)?.floor_modifications.get_mut(index)? {
+            FloorModification::MovingPlatform(moving_platform) => Some(moving_platform),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+// Index of the waypoint of the selected moving platform under the cursor, the start of the path can't be dragged this way.
+fn get_hovered_waypoint(level_asset: &LevelAsset, selected: Option<EditorItem>, cursor_position: Vec2, radius: f32) -> Option<usize> {
+    let Some(FloorModification::MovingPlatform(moving_platform)) = get_floor_modification(level_asset, selected?) else {
+        return None;
+    };
+
+    (0..moving_platform.waypoints.len()).find(|index| moving_platform.get_path_point(index + 1).truncate().distance(cursor_position) <= radius)
+}
+
+fn get_item_info(level_asset: &LevelAsset, item: EditorItem) -> Option<EntityInfo> {
+    let section = level_asset.sections.get(item.get_section())?;
+
+    match item {
+        EditorItem::Floor(_, index) => section.floors.get(index).map(|floor| EntityInfo { position: floor.position, size: floor.size }),
+        EditorItem::Transition(_, index) => section.transitions.get(index).map(|transition| transition.floor_info),
+        EditorItem::Door(_, index) => section.doors.get(index).map(|door| door.floor_info),
+        EditorItem::FloorModification(_, index) => match section.floor_modifications.get(index)? {
+            FloorModification::JumpPad(jump_pad) => Some(jump_pad.floor_info),
+            FloorModification::GravityInverter(gravity_inverter) => Some(gravity_inverter.floor_info),
+            FloorModification::TimeTrial(time_trial) => Some(time_trial.lever_info),
+            FloorModification::IllusoryWall(illusory_wall) => Some(EntityInfo { position: illusory_wall.position, size: illusory_wall.size }),
+            FloorModification::Decoration(decoration) => Some(EntityInfo { position: decoration.position, size: decoration.size }),
+            FloorModification::Hazard(hazard) => Some(hazard.floor_info),
+            FloorModification::Checkpoint(checkpoint) => Some(checkpoint.floor_info),
+            FloorModification::MovingPlatform(moving_platform) => Some(EntityInfo { position: moving_platform.floor_info.position, size: moving_platform.floor_info.size }),
+            FloorModification::PlatformLever(platform_lever) => Some(platform_lever.lever_info),
+            FloorModification::CrumblingPlatform(crumbling_platform) => Some(EntityInfo { position: crumbling_platform.floor_info.position, size: crumbling_platform.floor_info.size }),
+        },
+        EditorItem::Npc(_, index) => section.npcs.get(index).map(|npc| npc.floor_info),
+    }
+}
+
+fn set_item_info(level_asset: &mut LevelAsset, item: EditorItem, info: EntityInfo) {
+    let Some(section) = level_asset.sections.get_mut(item.get_section()) else {
+        return;
+    };
+
+    match item {
+        EditorItem::Floor(_, index) => {
+            if let Some(floor) = section.floors.get_mut(index) {
+                floor.position = info.position;
+                floor.size = info.size;
+            }
+        },
+        EditorItem::Transition(_, index) => {
+            if let Some(transition) = section.transitions.get_mut(index) {
+                transition.floor_info = info;
+            }
+        },
+        EditorItem::Door(_, index) => {
+            if let Some(door) = section.doors.get_mut(index) {
+                door.floor_info = info;
+            }
+        },
+        EditorItem::FloorModification(_, index) => {
+            match section.floor_modifications.get_mut(index) {
+                Some(FloorModification::JumpPad(jump_pad)) => jump_pad.floor_info = info,
+                Some(FloorModification::GravityInverter(gravity_inverter)) => gravity_inverter.floor_info = info,
+                Some(FloorModification::TimeTrial(time_trial)) => time_trial.lever_info = info,
+                Some(FloorModification::IllusoryWall(illusory_wall)) => {
+                    illusory_wall.position = info.position;
+                    illusory_wall.size = info.size;
+                },
+                Some(FloorModification::Decoration(decoration)) => {
+                    decoration.position = info.position;
+                    decoration.size = info.size;
+                },
+                Some(FloorModification::Hazard(hazard)) => hazard.floor_info = info,
+                Some(FloorModification::Checkpoint(checkpoint)) => checkpoint.floor_info = info,
+                Some(FloorModification::MovingPlatform(moving_platform)) => {
+                    moving_platform.floor_info.position = info.position;
+                    moving_platform.floor_info.size = info.size;
+                },
+                Some(FloorModification::PlatformLever(platform_lever)) => platform_lever.lever_info = info,
+                Some(FloorModification::CrumblingPlatform(crumbling_platform)) => {
+                    crumbling_platform.floor_info.position = info.position;
+                    crumbling_platform.floor_info.size = info.size;
+                },
+                None => {}
+            }
+        },
+        EditorItem::Npc(_, index) => {
+            if let Some(npc) = section.npcs.get_mut(index) {
+                npc.floor_info = info;
+            }
+        },
+    }
+}
+
+fn remove_item(level_asset: &mut LevelAsset, item: EditorItem) {
+    let Some(section) = level_asset.sections.get_mut(item.get_section()) else {
+        return;
+    };
+
+    match item {
+        EditorItem::Floor(_, index) if index < section.floors.len() => { section.floors.remove(index); },
+        EditorItem::Transition(_, index) if index < section.transitions.len() => { section.transitions.remove(index); },
+        EditorItem::Door(_, index) if index < section.doors.len() => { section.doors.remove(index); },
+        EditorItem::FloorModification(_, index) if index < section.floor_modifications.len() => { section.floor_modifications.remove(index); },
+        EditorItem::Npc(_, index) if index < section.npcs.len() => { section.npcs.remove(index); },
+        _ => {}
+    }
+}
+
+fn save_level_file(level: Level, level_asset: &LevelAsset) -> bool {
+    let asset_path = level.get_asset_path();
+    let path = get_asset_file_path(&asset_path);
+
+    if asset_path.ends_with(".tmx") {
+        warn!("Level {:?} is imported from Tiled, edit {} in Tiled instead", level, path.display());
+        return false;
+    }
+
+    let contents = match format_level_file(level_asset) {
+        Ok(contents) => contents,
+        Err(error) => {
+            warn!("Failed to serialize level {:?}: {}", level, error);
+            return false;
+        }
+    };
+
+    // Written next to the level file first, so a failed save can't leave a half written level behind.
+    if let Err(error) = write_user_data_file(&path, &contents) {
+        warn!("Failed to write level file {}: {}", path.display(), error);
+        return false;
+    }
+
+    info!("Saved level file {}", path.display());
+
+    true
+}
+
+// Lays the level file out the way the level files are written by hand, one line per entry,
+// so saving from the editor only touches the lines of the entries that changed.
+fn format_level_file(level_asset: &LevelAsset) -> ron::Result<String> {
+    let mut contents = String::from("(\n");
+
+    if level_asset.bgm.is_some() {
+        contents += &format!("    bgm: {},\n", to_inline_ron(&level_asset.bgm)?);
+    }
+
+    contents += &format!("    background: {},\n", to_inline_ron(&level_asset.background)?);
+    contents += "    sections: [\n";
+
+    for section in level_asset.sections.iter() {
+        contents += "        (\n";
+
+        if !section.progression.is_always() {
+            contents += "            progression: (\n";
+
+            if section.progression.from.is_some() {
+                contents += &format!("                from: {},\n", to_inline_ron(&section.progression.from)?);
+            }
+
+            if section.progression.until.is_some() {
+                contents += &format!("                until: {},\n", to_inline_ron(&section.progression.until)?);
+            }
+
+            contents += "            ),\n";
+        }
+
+        format_entries(&mut contents, "floors", &section.floors, to_inline_ron)?;
+        format_entries(&mut contents, "transitions", &section.transitions, to_inline_ron)?;
+        format_entries(&mut contents, "doors", &section.doors, to_inline_ron)?;
+        format_entries(&mut contents, "floor_modifications", &section.floor_modifications, format_floor_modification)?;
+        format_entries(&mut contents, "npcs", &section.npcs, to_inline_ron)?;
+
+        contents += "        ),\n";
+    }
+
+    contents += "    ],\n)\n";
+
+    Ok(contents)
+}
+
+fn format_entries<T>(contents: &mut String, name: &str, entries: &[T], format_entry: impl Fn(&T) -> ron::Result<String>) -> ron::Result<()> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    *contents += &format!("            {}: [\n", name);
+
+    for entry in entries {
+        *contents += &format!("                {},\n", format_entry(entry)?);
+    }
+
+    *contents += "            ],\n";
+
+    Ok(())
+}
+
+// Time trials list every floor they put up on its own line.
+fn format_floor_modification(floor_modification: &FloorModification) -> ron::Result<String> {
+    let FloorModification::TimeTrial(time_trial) = floor_modification else {
+        return to_inline_ron(floor_modification);
+    };
+
+    let mut contents = format!("TimeTrial((lever_info: {}, floor_infos: [\n", to_inline_ron(&time_trial.lever_info)?);
+
+    for floor_info in time_trial.floor_infos.iter() {
+        contents += &format!("                    {},\n", to_inline_ron(floor_info)?);
+    }
+
+    contents += &format!("                ], seconds_to_complete: {}, id: {}))", time_trial.seconds_to_complete, time_trial.id);
+
+    Ok(contents)
+}
+
+fn to_inline_ron<T: Serialize>(value: &T) -> ron::Result<String> {
+    ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default().depth_limit(0))
+}
+
+fn get_top_left(info: &EntityInfo) -> Vec2 {
+    Vec2::new(info.position.x - info.size.x / 2., info.position.y + info.size.y / 2.)
+}
+
+fn get_bottom_right(info: &EntityInfo) -> Vec2 {
+    Vec2::new(info.position.x + info.size.x / 2., info.position.y - info.size.y / 2.)
+}
+
+fn get_zoom(projection: &Projection) -> f32 {
+    match projection {
+        Projection::Orthographic(orthographic) => orthographic.scale,
+        _ => 1.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    // Every level file is written the way the editor saves it, so formatting one and parsing it back has to give the same file.
+    #[test]
+    fn formatted_level_files_parse_back() {
+        for entry in fs::read_dir("assets/levels").unwrap() {
+            let path = entry.unwrap().path();
+            let contents = fs::read_to_string(&path).unwrap();
+
+            let level_asset = ron::de::from_bytes::<LevelAsset>(contents.as_bytes()).unwrap();
+            let formatted = format_level_file(&level_asset).unwrap();
+            let parsed = ron::de::from_bytes::<LevelAsset>(formatted.as_bytes()).unwrap_or_else(|error| panic!("{}: {}", path.display(), error));
+
+            assert_eq!(formatted, contents, "{}", path.display());
+            assert_eq!(format_level_file(&parsed).unwrap(), formatted, "{}", path.display());
+        }
+    }
+}
@@ -1,14 +1,23 @@
 use std::time::Duration;
 
-use bevy::{audio::{PlaybackMode, Volume}, prelude::*};
+use bevy::{audio::{PlaybackMode, Volume}, ecs::system::SystemParam, prelude::*};
 use bevy_rapier2d::{prelude::*, rapier::prelude::CollisionEventFlags};
 
-use crate::{audio_settings::AudioSettings, interactable::{interaction_state::InteractionState, Interactable}, movement::{Jumper, Movable}, npc::NPC, Cweampuff};
+use crate::{audio_settings::AudioSettings, death::{DeathState, RespawnPoint}, health::{DamageEvent, Health}, interactable::{interaction_state::InteractionState, Interactable}, movement::{Jumper, Movable}, npc::NPC, Cweampuff, CWEAMPUFF_DIAMETER};
 use crate::asset_loader::load_asset;
+use crate::controls::{ActionInput, GameAction};
 use crate::movement::check_entities;
-use super::level_layout::{BreakableWall, DoorCollider, FloorAssetType, FloorCollider, GravityInverter, JumpPad, TimeTrial};
+use crate::save::SaveGameEvent;
+use super::LevelLayout;
+use super::level_layout::{BreakableWall, Checkpoint, CollisionType, CrumbleState, CrumblingPlatform, DoorCollider, FloorAssetType, FloorCollider, GravityInverter, Hazard, JumpPad, MovingPlatform, PlatformLever, TimeTrial};
 
 const JUMP_PAD_VELOCITY_DELTA: f32 = 2.;
+const CRUMBLE_SHAKE_AMPLITUDE: f32 = 4.;
+// How many times per second a shaking platform jumps to a new spot.
+const CRUMBLE_SHAKE_RATE: f32 = 30.;
+// A fallen platform drops this far while it fades out.
+const CRUMBLE_FALL_DISTANCE: f32 = 150.;
+const CRUMBLE_FALL_SECS: f32 = 0.5;
 
 #[derive(Component)]
 pub struct TimeTrialTimer {
@@ -16,6 +25,13 @@ pub struct TimeTrialTimer {
     pub entity_id: u32
 }
 
+#[derive(Component)]
+pub struct CrumblingPlatformSprite;
+
+type CrumblingPlatformSpriteQuery<'a> = (&'a mut Sprite, &'a mut Transform);
+
+type InteractableFloorModification = (With<Interactable>, Without<NPC>);
+
 pub fn jump_pad_collision_reader(
     jump_pads: Query<Entity, (With<Sensor>, With<JumpPad>, Without<NPC>, Without<DoorCollider>)>,
     mut cweampuff: Single<(Entity, &mut Velocity, &Jumper), With<Cweampuff>>,
@@ -46,6 +62,47 @@ pub fn jump_pad_collision_reader(
     }
 }
 
+type HazardSensor<'a> = (Entity, &'a Hazard, &'a Transform);
+
+type HazardSensorFilter = (With<Sensor>, Without<NPC>, Without<DoorCollider>);
+
+pub fn hazard_collision_reader(
+    hazards: Query<HazardSensor, HazardSensorFilter>,
+    mut cweampuff: Single<(Entity, &mut Health), With<Cweampuff>>,
+    mut contact_events: EventReader<CollisionEvent>,
+    mut damage_writer: EventWriter<DamageEvent>,
+    mut death_state: ResMut<NextState<DeathState>>,
+) {
+    let (cweampuff_entity, cweampuff_health) = &mut *cweampuff;
+
+    for event in contact_events.read() {
+        match event {
+            CollisionEvent::Started(h1, h2, _) => {
+                let Some((hazard_entity, hazard, hazard_transform)) = hazards.iter().find(|(hazard_entity, ..)| check_entities(h1, h2, hazard_entity, cweampuff_entity)) else {
+                    continue;
+                };
+
+                // The rest of this frame's events are still read, instead of being left for the next frame.
+                let Some(amount) = hazard.damage else {
+                    death_state.set(DeathState::Dying);
+
+                    continue;
+                };
+
+                let damage = DamageEvent { amount, source: hazard_transform.translation };
+
+                cweampuff_health.start_touching(hazard_entity, damage);
+                damage_writer.write(damage);
+            },
+            CollisionEvent::Stopped(h1, h2, _) => {
+                if let Some((hazard_entity, ..)) = hazards.iter().find(|(hazard_entity, ..)| check_entities(h1, h2, hazard_entity, cweampuff_entity)) {
+                    cweampuff_health.stop_touching(hazard_entity);
+                }
+            }
+        }
+    }
+}
+
 pub fn gravity_inverter_collision_reader(
     jump_pads: Query<Entity, (With<Sensor>, With<GravityInverter>, Without<NPC>, Without<DoorCollider>)>,
     mut cweampuff: Single<(Entity, &mut Jumper, &mut GravityScale, &mut Movable), With<Cweampuff>>,
@@ -96,8 +153,264 @@ pub fn gravity_inverter_collision_reader(
     }
 }
 
+pub fn checkpoint_collision_reader(
+    mut checkpoints: Query<(Entity, &mut Checkpoint), InteractableFloorModification>,
+    cweampuff: Single<Entity, With<Cweampuff>>,
+    mut contact_events: EventReader<CollisionEvent>,
+    mut interaction_state: ResMut<NextState<InteractionState>> 
+) {
+    for event in contact_events.read() {
+        if let CollisionEvent::Stopped(h1, h2, _) = event {
+            for (checkpoint_entity, mut checkpoint) in checkpoints.iter_mut() {
+                if check_entities(h1, h2, &checkpoint_entity, &cweampuff) {
+                    checkpoint.is_active = false;
+                    interaction_state.set(InteractionState::NotReady);
+
+                    return;
+                }
+            }
+        }
+    
+        if let CollisionEvent::Started(h1, h2, _) = event {
+            for (checkpoint_entity, mut checkpoint) in checkpoints.iter_mut() {
+                if check_entities(h1, h2, &checkpoint_entity, &cweampuff) {
+                    checkpoint.is_active = true;
+                    interaction_state.set(InteractionState::Ready);
+
+                    return;
+                }
+            }
+        }
+    }
+}
+
+// Resting at a bench makes it the respawn point, in whatever level it's in, and saves the game.
+#[derive(SystemParam)]
+pub struct CheckpointRest<'w> {
+    level_layout: Single<'w, &'static LevelLayout>,
+    respawn_point: ResMut<'w, RespawnPoint>,
+    save_writer: EventWriter<'w, SaveGameEvent>,
+}
+
+impl CheckpointRest<'_> {
+    fn rest_at(&mut self, position: Vec3) {
+        self.respawn_point.position = position;
+        self.respawn_point.checkpoint_level = Some(self.level_layout.level);
+
+        self.save_writer.write(SaveGameEvent);
+    }
+}
+
+pub fn checkpoint_start_interaction_input_reader(
+    action_input: ActionInput,
+    checkpoints: Query<&Checkpoint, InteractableFloorModification>,
+    mut cweampuff: Single<(&Transform, &mut Health), With<Cweampuff>>,
+    mut checkpoint_rest: CheckpointRest,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    audio_settings: Res<AudioSettings>,
+) {
+    if !action_input.just_pressed(GameAction::Interact) {
+        return;
+    }
+
+    if checkpoints.iter().any(|f| f.is_active) {
+        let (cweampuff_transform, cweampuff_health) = &mut *cweampuff;
+
+        cweampuff_health.heal();
+        checkpoint_rest.rest_at(cweampuff_transform.translation);
+
+        let mut playback_settings = PlaybackSettings::default().with_volume(Volume::Linear(audio_settings.sfx_volume));
+        playback_settings.mode = PlaybackMode::Despawn;
+    
+        commands.spawn((
+            AudioPlayer::new(load_asset(&asset_server, "sfx/checkpoint.wav")),
+            playback_settings
+        ));
+    }
+}
+
+// Runs in fixed time, so the platforms move the same distance no matter the frame rate.
+// The Cweampuff rides along with whatever platform it's standing on, which is its floor even while gravity is inverted.
+pub fn move_platforms(
+    mut platforms: Query<(&mut MovingPlatform, &mut Transform, &FloorCollider), Without<Cweampuff>>,
+    mut cweampuff: Single<&mut Transform, With<Cweampuff>>,
+    time: Res<Time>,
+) {
+    for (mut platform, mut platform_transform, floor_collider) in platforms.iter_mut() {
+        if !platform.is_running || platform.waypoints.is_empty() {
+            continue;
+        }
+
+        let start = platform_transform.translation;
+        let mut position = start;
+        let mut distance_left = platform.speed * time.delta_secs();
+
+        // A platform fast enough to pass several waypoints in one step still shouldn't go around its path more than once.
+        for _ in 0..=platform.waypoints.len() {
+            let target = platform.get_path_point(platform.next_waypoint);
+            let distance_to_target = position.distance(target);
+
+            if distance_to_target > distance_left {
+                position += (target - position).normalize_or_zero() * distance_left;
+                break;
+            }
+
+            position = target;
+            distance_left -= distance_to_target;
+            platform.advance_waypoint();
+        }
+
+        platform_transform.translation = position;
+
+        if let Some(CollisionType::Floor) = floor_collider.currently_touching_side {
+            cweampuff.translation += position - start;
+        }
+    }
+}
+
+pub fn crumbling_platform_monitor(
+    mut platforms: Query<(Entity, &mut CrumblingPlatform, &mut FloorCollider, &Children)>,
+    mut sprites: Query<CrumblingPlatformSpriteQuery, (With<CrumblingPlatformSprite>, Without<Cweampuff>)>,
+    cweampuff: Single<&Transform, With<Cweampuff>>,
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    audio_settings: Res<AudioSettings>,
+) {
+    for (platform_entity, mut platform, mut floor_collider, children) in platforms.iter_mut() {
+        let Some(sprite_entity) = children.iter().find(|child| sprites.contains(*child)) else {
+            continue;
+        };
+
+        let Ok((mut sprite, mut sprite_transform)) = sprites.get_mut(sprite_entity) else {
+            continue;
+        };
+
+        platform.time_passed += time.delta_secs();
+
+        match platform.state {
+            CrumbleState::Solid => {
+                // Standing on it counts the same while gravity is inverted, since the underside is its floor then.
+                if let Some(CollisionType::Floor) = floor_collider.currently_touching_side {
+                    platform.state = CrumbleState::Shaking;
+                    platform.time_passed = 0.;
+                    sprite.image = load_asset(&asset_server, "floor_modifications/CrumblingPlatformCracked.png");
+
+                    let mut playback_settings = PlaybackSettings::default().with_volume(Volume::Linear(audio_settings.sfx_volume));
+                    playback_settings.mode = PlaybackMode::Despawn;
+
+                    commands.spawn((
+                        AudioPlayer::new(load_asset(&asset_server, "sfx/crumble.wav")),
+                        playback_settings
+                    ));
+                }
+            },
+            CrumbleState::Shaking => {
+                if platform.time_passed >= platform.crumble_secs {
+                    platform.state = CrumbleState::Fallen;
+                    platform.time_passed = 0.;
+                    sprite_transform.translation = Vec3::ZERO;
+
+                    // Contacts of a disabled collider are removed without going through the side the Cweampuff was touching, so that's forgotten here.
+                    floor_collider.currently_touching_side = None;
+                    commands.entity(platform_entity).insert(ColliderDisabled);
+
+                    continue;
+                }
+
+                let seed = (platform.time_passed * CRUMBLE_SHAKE_RATE).floor();
+                sprite_transform.translation = Vec3::new((seed * 12.9898).sin(), (seed * 78.233).sin(), 0.) * CRUMBLE_SHAKE_AMPLITUDE;
+            },
+            CrumbleState::Fallen => {
+                let progress = (platform.time_passed / CRUMBLE_FALL_SECS).min(1.);
+
+                sprite_transform.translation.y = -CRUMBLE_FALL_DISTANCE * progress * progress;
+                sprite.color.set_alpha(1. - progress);
+
+                // Coming back while the Cweampuff is in the way would shove it out of the platform.
+                let half_size = (platform.floor_info.size + CWEAMPUFF_DIAMETER) / 2.;
+                let is_in_the_way = (cweampuff.translation - platform.floor_info.position).truncate().abs().cmplt(half_size).all();
+
+                if platform.time_passed >= platform.respawn_secs && !is_in_the_way {
+                    platform.state = CrumbleState::Solid;
+                    platform.time_passed = 0.;
+                    sprite.image = load_asset(&asset_server, "floor_modifications/CrumblingPlatform.png");
+                    sprite.color.set_alpha(1.);
+                    sprite_transform.translation = Vec3::ZERO;
+
+                    commands.entity(platform_entity).remove::<ColliderDisabled>();
+                }
+            }
+        }
+    }
+}
+
+pub fn platform_lever_collision_reader(
+    mut platform_levers: Query<(Entity, &mut PlatformLever), InteractableFloorModification>,
+    cweampuff: Single<Entity, With<Cweampuff>>,
+    mut contact_events: EventReader<CollisionEvent>,
+    mut interaction_state: ResMut<NextState<InteractionState>> 
+) {
+    for event in contact_events.read() {
+        if let CollisionEvent::Stopped(h1, h2, _) = event {
+            for (platform_lever_entity, mut platform_lever) in platform_levers.iter_mut() {
+                if check_entities(h1, h2, &platform_lever_entity, &cweampuff) {
+                    platform_lever.is_active = false;
+                    interaction_state.set(InteractionState::NotReady);
+
+                    return;
+                }
+            }
+        }
+    
+        if let CollisionEvent::Started(h1, h2, _) = event {
+            for (platform_lever_entity, mut platform_lever) in platform_levers.iter_mut() {
+                if check_entities(h1, h2, &platform_lever_entity, &cweampuff) {
+                    platform_lever.is_active = true;
+                    interaction_state.set(InteractionState::Ready);
+
+                    return;
+                }
+            }
+        }
+    }
+}
+
+pub fn platform_lever_start_interaction_input_reader(
+    action_input: ActionInput,
+    mut platform_levers: Query<(&mut PlatformLever, &mut Sprite), InteractableFloorModification>,
+    mut platforms: Query<&mut MovingPlatform>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    audio_settings: Res<AudioSettings>,
+) {
+    if !action_input.just_pressed(GameAction::Interact) {
+        return;
+    }
+
+    if let Some((mut platform_lever, mut platform_lever_sprite)) = platform_levers.iter_mut().find(|f| f.0.is_active) {
+        platform_lever.is_pulled = !platform_lever.is_pulled;
+
+        let texture = if platform_lever.is_pulled { "floor_modifications/Lever2.png" } else { "floor_modifications/Lever1.png" };
+        platform_lever_sprite.image = load_asset(&asset_server, texture);
+
+        for mut platform in platforms.iter_mut().filter(|f| f.lever_id == Some(platform_lever.id)) {
+            platform.is_running = platform_lever.is_pulled;
+        }
+
+        let mut playback_settings = PlaybackSettings::default().with_volume(Volume::Linear(audio_settings.sfx_volume));
+        playback_settings.mode = PlaybackMode::Despawn;
+    
+        commands.spawn((
+            AudioPlayer::new(load_asset(&asset_server, "sfx/lever.wav")),
+            playback_settings
+        ));
+    }
+}
+
 pub fn time_trial_collision_reader(
-    mut time_trials: Query<(Entity, &mut TimeTrial), (With<Interactable>, Without<NPC>)>,
+    mut time_trials: Query<(Entity, &mut TimeTrial), InteractableFloorModification>,
     cweampuff: Single<Entity, With<Cweampuff>>,
     mut contact_events: EventReader<CollisionEvent>,
     mut interaction_state: ResMut<NextState<InteractionState>> 
@@ -128,14 +441,14 @@ pub fn time_trial_collision_reader(
 }
 
 pub fn time_trial_start_interaction_input_reader(
-    keyboard_input: Res<ButtonInput<KeyCode>>, 
-    mut time_trials: Query<(&TimeTrial, &mut Sprite), (With<Interactable>, Without<NPC>)>,
+    action_input: ActionInput,
+    mut time_trials: Query<(&TimeTrial, &mut Sprite), InteractableFloorModification>,
     mut timers: Query<&mut TimeTrialTimer>,
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     audio_settings: Res<AudioSettings>,
 ) {
-    if !keyboard_input.just_pressed(KeyCode::KeyE) {
+    if !action_input.just_pressed(GameAction::Interact) {
         return;
     }
 
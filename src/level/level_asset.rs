@@ -0,0 +1,89 @@
+use bevy::{asset::{io::Reader, AssetLoader, LoadContext}, prelude::*};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::Cweampuff;
+
+use super::{level_layout::{DoorCollider, FloorAssetType, FloorInfo, FloorModification, NpcPlacement, TransitionCollider}, progression::Progression};
+
+#[derive(Asset, TypePath, Serialize, Deserialize)]
+pub struct LevelAsset {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bgm: Option<String>,
+    pub background: FloorAssetType,
+    pub sections: Vec<LevelSection>,
+}
+
+impl LevelAsset {
+    pub fn get_sections(&self, cweampuff: &Cweampuff) -> impl Iterator<Item = &LevelSection> {
+        let progression = cweampuff.progression;
+
+        self.sections.iter().filter(move |section| section.progression.contains(progression))
+    }
+}
+
+// A group of level entries that only exist while the Cweampuff's progression is within the given range.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct LevelSection {
+    #[serde(skip_serializing_if = "ProgressionRange::is_always")]
+    pub progression: ProgressionRange,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub floors: Vec<FloorInfo>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub transitions: Vec<TransitionCollider>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub doors: Vec<DoorCollider>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub floor_modifications: Vec<FloorModification>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub npcs: Vec<NpcPlacement>,
+}
+
+// `from` is inclusive and `until` is exclusive, a missing bound leaves that side open.
+#[derive(Serialize, Deserialize, Default, Clone, Copy)]
+#[serde(default)]
+pub struct ProgressionRange {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<Progression>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<Progression>,
+}
+
+impl ProgressionRange {
+    pub fn contains(&self, progression: Progression) -> bool {
+        self.from.is_none_or(|from| progression >= from) && self.until.is_none_or(|until| progression < until)
+    }
+
+    pub fn is_always(&self) -> bool {
+        self.from.is_none() && self.until.is_none()
+    }
+}
+
+#[derive(Default)]
+pub struct LevelAssetLoader;
+
+#[derive(Debug, Error)]
+pub enum LevelAssetLoaderError {
+    #[error("Could not read level file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse level file: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for LevelAssetLoader {
+    type Asset = LevelAsset;
+    type Settings = ();
+    type Error = LevelAssetLoaderError;
+
+    async fn load(&self, reader: &mut dyn Reader, _settings: &(), _load_context: &mut LoadContext<'_>) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        Ok(ron::de::from_bytes::<LevelAsset>(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["level.ron"]
+    }
+}
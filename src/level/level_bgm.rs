@@ -21,7 +21,7 @@ pub fn set_bgm_state(
     asset_server: Res<AssetServer>,
 ) {
     for level_layout in level_layout_query.iter() {
-        match level_layout.bgm {
+        match &level_layout.bgm {
             Some(bgm) => {
                 if bgm_query.is_empty() {
                     next_bgm_state.set(LevelBGMState::Changing);
@@ -1,28 +1,6 @@
-pub mod starting_room_layout;
-pub mod cweamcat_lair_layout;
-pub mod cweamcat_house_layout;
-pub mod hell_1_layout;
-pub mod hell_2_layout;
-pub mod hell_3_layout;
-pub mod hell_4_layout;
-pub mod cerber_lair_layout;
-pub mod spaceship_1_layout;
-pub mod spaceship_2_layout;
-pub mod spaceship_3_layout;
-pub mod spaceship_4_layout;
-pub mod aquwa_lair_layout;
-pub mod factory_transition_layout;
-pub mod factory_1_layout;
-pub mod factory_2_layout;
-pub mod factory_3_layout;
-pub mod factory_4_layout;
-pub mod neuro_lair_layout;
-pub mod factory_hidden_level_layout;
-
 use bevy::{ecs::component::Component, math::{Vec2, Vec3}};
-use crate::{npc::NPC, Cweampuff};
-
-use super::Level;
+use serde::{Deserialize, Serialize};
+use super::{level_asset::ProgressionRange, Level};
 
 pub enum CollisionType {
     Floor,
@@ -31,7 +9,7 @@ pub enum CollisionType {
     Ceiling
 }
 
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 pub enum FloorAssetType {
     #[default]
     Forest,
@@ -46,12 +24,12 @@ pub struct FloorCollider {
     pub currently_touching_side: Option<CollisionType>
 }
 
-#[derive(Component, Default, Clone, Copy)]
+#[derive(Component, Default, Clone, Copy, Serialize, Deserialize)]
 pub struct BreakableWall {
     pub index: u32
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
 pub struct TransitionCollider {
     pub exit_index: u32,
     pub floor_info: EntityInfo,
@@ -59,7 +37,7 @@ pub struct TransitionCollider {
     pub transition_to_level: Level
 }
 
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
 pub enum DoorType {
     #[default]
     Door,
@@ -67,76 +45,228 @@ pub enum DoorType {
     MilkHouse
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
 pub struct DoorCollider {
     pub floor_info: EntityInfo,
     pub transition_to_level: Level,
     pub safe_position: Vec3,
+    #[serde(skip)]
     pub is_active: bool,
+    #[serde(default)]
     pub door_type: DoorType,
 }
 
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
 pub struct FloorInfo {
     pub position: Vec3,
     pub size: Vec2,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub breakable_wall: Option<BreakableWall>,
     pub floor_asset: FloorAssetType
 }
 
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
 pub struct EntityInfo {
     pub position: Vec3,
     pub size: Vec2
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
 pub struct JumpPad {
     pub floor_info: EntityInfo
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
 pub struct GravityInverter {
     pub floor_info: EntityInfo
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum HazardType {
+    #[default]
+    Spikes,
+    Lava,
+    Electric
+}
+
+impl HazardType {
+    // Hazards look like they belong in the level unless they say otherwise.
+    pub fn from_background(background: FloorAssetType) -> Self {
+        match background {
+            FloorAssetType::Hell => HazardType::Lava,
+            FloorAssetType::Spaceship | FloorAssetType::Factory => HazardType::Electric,
+            FloorAssetType::Forest | FloorAssetType::CweamcatHouse => HazardType::Spikes
+        }
+    }
+}
+
+// Touching a hazard kills the Cweampuff, unless it has `damage`, then it hurts it and knocks it back out.
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
+pub struct Hazard {
+    pub floor_info: EntityInfo,
+    #[serde(default)]
+    pub hazard_type: HazardType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub damage: Option<u32>
+}
+
+// Resting here sets where the Cweampuff respawns, heals it and saves the game.
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub floor_info: EntityInfo,
+    #[serde(skip)]
+    pub is_active: bool
+}
+
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct TimeTrial {
     pub lever_info: EntityInfo,
-    pub floor_infos: &'static [FloorInfo],
+    pub floor_infos: Vec<FloorInfo>,
     pub seconds_to_complete: u64,
     pub id: u32,
+    #[serde(skip)]
     pub is_active: bool
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum PlatformPathMode {
+    // Goes through the waypoints and then back the same way.
+    #[default]
+    PingPong,
+    // Goes from the last waypoint straight back to where it started.
+    Loop
+}
+
+pub const DEFAULT_PLATFORM_SPEED: f32 = 200.;
+
+// A floor that travels along its waypoints, carrying the Cweampuff standing on it.
+// Waypoints are offsets from where the platform starts, so moving the platform moves its whole path.
+#[derive(Component, Clone, Serialize, Deserialize)]
+pub struct MovingPlatform {
+    pub floor_info: FloorInfo,
+    pub waypoints: Vec<Vec2>,
+    pub speed: f32,
+    #[serde(default)]
+    pub path_mode: PlatformPathMode,
+    // Platforms with a lever stand still until a platform lever with the same id is pulled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lever_id: Option<u32>,
+    #[serde(skip)]
+    pub is_running: bool,
+    #[serde(skip)]
+    pub next_waypoint: usize,
+    #[serde(skip)]
+    pub is_reversed: bool
+}
+
+impl MovingPlatform {
+    // A hand edited level file could ask for a platform that never moves or never reaches its next waypoint.
+    pub fn sanitized(self) -> Self {
+        if self.speed.is_finite() && self.speed > 0. {
+            return self;
+        }
+
+        Self {
+            speed: DEFAULT_PLATFORM_SPEED,
+            ..self
+        }
+    }
+
+    // The start of the path isn't one of the waypoints, so it comes first.
+    pub fn get_path_point(&self, index: usize) -> Vec3 {
+        let offset = match index {
+            0 => Vec2::ZERO,
+            _ => self.waypoints[index - 1]
+        };
+
+        self.floor_info.position + offset.extend(0.)
+    }
+
+    pub fn advance_waypoint(&mut self) {
+        let last_point = self.waypoints.len();
+
+        match self.path_mode {
+            PlatformPathMode::Loop => self.next_waypoint = (self.next_waypoint + 1) % (last_point + 1),
+            PlatformPathMode::PingPong => {
+                if self.next_waypoint >= last_point {
+                    self.is_reversed = true;
+                }
+                else if self.next_waypoint == 0 {
+                    self.is_reversed = false;
+                }
+
+                self.next_waypoint = if self.is_reversed { self.next_waypoint - 1 } else { self.next_waypoint + 1 };
+            }
+        }
+    }
+}
+
+// Starts and stops every moving platform with the same lever id.
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
+pub struct PlatformLever {
+    pub lever_info: EntityInfo,
+    pub id: u32,
+    #[serde(skip)]
+    pub is_active: bool,
+    #[serde(skip)]
+    pub is_pulled: bool
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum CrumbleState {
+    #[default]
+    Solid,
+    Shaking,
+    Fallen
+}
+
+// A floor that starts shaking once the Cweampuff lands on it, falls `crumble_secs` later and comes back after `respawn_secs`.
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
+pub struct CrumblingPlatform {
+    pub floor_info: FloorInfo,
+    pub crumble_secs: f32,
+    pub respawn_secs: f32,
+    #[serde(skip)]
+    pub state: CrumbleState,
+    // Time spent in the current state.
+    #[serde(skip)]
+    pub time_passed: f32
+}
+
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
 pub struct IllusoryWall {
     pub position: Vec3,
     pub size: Vec2,
     pub floor_asset: FloorAssetType
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct Decoration {
     pub position: Vec3,
     pub size: Vec2,
-    pub asset: &'static str
+    pub asset: String
 }
 
+#[derive(Clone, Serialize, Deserialize)]
 pub enum FloorModification {
     JumpPad(JumpPad),
     GravityInverter(GravityInverter),
     TimeTrial(TimeTrial),
     IllusoryWall(IllusoryWall),
     Decoration(Decoration),
+    Hazard(Hazard),
+    Checkpoint(Checkpoint),
+    MovingPlatform(MovingPlatform),
+    PlatformLever(PlatformLever),
+    CrumblingPlatform(CrumblingPlatform),
 }
 
-pub trait LevelInfo: Sync + Send {
-    fn get_floor_info(&self, cweampuff: &Cweampuff) -> Box<[FloorInfo]>;
-    fn get_transitions_info(&self, cweampuff: &Cweampuff) -> Option<Box<[TransitionCollider]>>;
-    fn get_doors(&self, cweampuff: &Cweampuff) -> Option<Box<[DoorCollider]>>;
-    fn get_npcs(&self, cweampuff: &Cweampuff) -> Option<Box<[NPC]>>;
-    fn get_floor_modifications(&self, cweampuff: &Cweampuff) -> Option<Box<[FloorModification]>>;
-    fn get_bgm(&self) -> Option<&'static str>;
-    fn get_background(&self) -> FloorAssetType;
-}
\ No newline at end of file
+// An NPC standing in the level, what it says and does afterwards comes from its dialogue file.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct NpcPlacement {
+    pub name: String,
+    pub floor_info: EntityInfo,
+    // NPCs come and go as the story moves on, on top of the progression range of their section.
+    #[serde(default, skip_serializing_if = "ProgressionRange::is_always")]
+    pub progression: ProgressionRange,
+}
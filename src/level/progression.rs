@@ -1,6 +1,8 @@
 use std::fmt::Display;
 
-#[derive(Clone, Copy, Default, Debug, PartialEq, PartialOrd)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub enum Progression {
     #[default]
     None, 
@@ -12,8 +14,32 @@ pub enum Progression {
     RisingStar
 }
 
+impl Progression {
+    pub fn get_localization_id(&self) -> &'static str {
+        match self {
+            Progression::None => "progression.none",
+            Progression::MetMilk => "progression.met_milk",
+            Progression::HasCherish => "progression.has_cherish",
+            Progression::MilkWokeUp => "progression.milk_woke_up",
+            Progression::HasLetter => "progression.has_letter",
+            Progression::GivenLetter => "progression.given_letter",
+            Progression::RisingStar => "progression.rising_star",
+        }
+    }
+}
+
 impl Display for Progression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+        let label = match self {
+            Progression::None => "The Beginning",
+            Progression::MetMilk => "Met Milk",
+            Progression::HasCherish => "Has Cherish",
+            Progression::MilkWokeUp => "Milk Woke Up",
+            Progression::HasLetter => "Has Letter",
+            Progression::GivenLetter => "Given Letter",
+            Progression::RisingStar => "Rising Star",
+        };
+
+        write!(f, "{}", label)
     }
 }
\ No newline at end of file
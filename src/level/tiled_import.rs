@@ -0,0 +1,566 @@
+use std::collections::HashMap;
+
+use bevy::{asset::{io::Reader, AssetLoader, LoadContext}, prelude::*};
+use roxmltree::{Document, Node};
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+use crate::CWEAMPUFF_Z_INDEX;
+
+use super::{
+    level_asset::{LevelAsset, LevelSection, ProgressionRange},
+    level_layout::{BreakableWall, CrumbleState, CrumblingPlatform, Decoration, DoorCollider, EntityInfo, FloorAssetType, FloorInfo, Checkpoint, FloorModification, GravityInverter, Hazard, HazardType, IllusoryWall, JumpPad, MovingPlatform, NpcPlacement, PlatformLever, TimeTrial, TransitionCollider},
+};
+
+// Tiled stores flipping and rotation in the top bits of a tile id.
+const TILE_FLIP_FLAGS: u32 = 0xF000_0000;
+
+// Imports levels authored in Tiled.
+//
+// Map properties: `bgm`, `background` (a `FloorAssetType`), `origin_x` and `origin_y` (world position of the map's top left corner).
+// Layer properties: `from` and `until` (a `Progression`) restrict the layer to part of the story.
+// Tile layers become floors, using the tileset's name as the `FloorAssetType`.
+// Objects are rectangles whose class picks what they turn into:
+// - `Floor`: `floor_asset`, `breakable_wall` (index)
+// - `Transition`: `exit_index`, `transition_to_level`, `safe_x`, `safe_y`
+// - `Door`: `transition_to_level`, `safe_x`, `safe_y`, `door_type`
+// - `JumpPad`, `GravityInverter`
+// - `TimeTrial`: `id`, `seconds_to_complete`
+// - `TimeTrialFloor`: `time_trial` (id), `floor_asset`, `breakable_wall` (index)
+// - `IllusoryWall`: `floor_asset`
+// - `Decoration`: `asset`
+// - `Hazard`: `hazard_type`, which defaults to the one that fits the map's background, `damage`, which makes it hurt instead of kill
+// - `Checkpoint`
+// - `MovingPlatform`: `waypoints` (a list of `(x, y)` pixel offsets from the platform), `speed`, `path_mode`, `floor_asset`, `lever` (id)
+// - `PlatformLever`: `id`
+// - `CrumblingPlatform`: `crumble_secs`, `respawn_secs`
+// - `Npc`: `npc` (its name), `from` and `until` to narrow down the layer's progression range
+// Every object also accepts `z` to override its default z-index.
+#[derive(Default)]
+pub struct TiledLevelLoader;
+
+#[derive(Debug, Error)]
+pub enum TiledLevelLoaderError {
+    #[error("Could not read Tiled map: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Tiled map is not valid UTF-8: {0}")]
+    Utf8(#[from] std::string::FromUtf8Error),
+    #[error("Could not parse Tiled map: {0}")]
+    Xml(#[from] roxmltree::Error),
+    #[error("Unsupported Tiled map: {0}")]
+    Unsupported(String),
+    #[error("Missing property `{0}` on {1}")]
+    MissingProperty(String, String),
+    #[error("Invalid value `{1}` for `{0}`")]
+    InvalidValue(String, String),
+}
+
+impl AssetLoader for TiledLevelLoader {
+    type Asset = LevelAsset;
+    type Settings = ();
+    type Error = TiledLevelLoaderError;
+
+    async fn load(&self, reader: &mut dyn Reader, _settings: &(), _load_context: &mut LoadContext<'_>) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        import_tiled_map(&String::from_utf8(bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tmx"]
+    }
+}
+
+struct MapInfo {
+    origin: Vec2,
+    tile_size: Vec2,
+    background: FloorAssetType,
+    // First tile id of every tileset, sorted in ascending order.
+    tilesets: Vec<(u32, FloorAssetType)>,
+}
+
+impl MapInfo {
+    // Tiled measures from the top left corner with y pointing down, levels are centered rectangles with y pointing up.
+    fn get_entity_info(&self, top_left: Vec2, size: Vec2, z: f32) -> EntityInfo {
+        let center = top_left + size / 2.;
+
+        EntityInfo {
+            position: Vec3::new(self.origin.x + center.x, self.origin.y - center.y, z),
+            size
+        }
+    }
+
+    fn get_tileset(&self, tile_id: u32) -> FloorAssetType {
+        self.tilesets.iter()
+            .rev()
+            .find(|(first_tile_id, _)| *first_tile_id <= tile_id)
+            .map(|(_, floor_asset)| *floor_asset)
+            .unwrap_or(self.background)
+    }
+}
+
+fn import_tiled_map(text: &str) -> Result<LevelAsset, TiledLevelLoaderError> {
+    let document = Document::parse(text)?;
+    let map = document.root_element();
+
+    if map.attribute("infinite") == Some("1") {
+        return Err(TiledLevelLoaderError::Unsupported("infinite maps can't be imported".to_string()));
+    }
+
+    let properties = get_properties(map);
+    let background = get_property(&properties, "background")?.unwrap_or_default();
+
+    let mut tilesets: Vec<(u32, FloorAssetType)> = map.children()
+        .filter(|node| node.has_tag_name("tileset"))
+        .map(|tileset| {
+            let first_tile_id = parse_value("firstgid", tileset.attribute("firstgid").unwrap_or("1"))?;
+
+            // External tilesets only name their file, so its name has to match the floor asset instead.
+            let name = tileset.attribute("name")
+                .or_else(|| tileset.attribute("source").and_then(|source| source.rsplit('/').next()?.split('.').next()))
+                .unwrap_or_default();
+
+            Ok((first_tile_id, parse_value("tileset", name).unwrap_or(background)))
+        })
+        .collect::<Result<_, TiledLevelLoaderError>>()?;
+
+    tilesets.sort_by_key(|(first_tile_id, _)| *first_tile_id);
+
+    let map_info = MapInfo {
+        origin: Vec2::new(get_property(&properties, "origin_x")?.unwrap_or(0.), get_property(&properties, "origin_y")?.unwrap_or(0.)),
+        tile_size: Vec2::new(parse_value("tilewidth", map.attribute("tilewidth").unwrap_or("0"))?, parse_value("tileheight", map.attribute("tileheight").unwrap_or("0"))?),
+        background,
+        tilesets
+    };
+
+    // Time trial floors can live on any layer, so they are gathered before the time trials that own them.
+    let mut time_trial_floors: HashMap<u32, Vec<FloorInfo>> = HashMap::new();
+
+    for object in map.descendants().filter(|node| node.has_tag_name("object") && get_object_class(*node) == "TimeTrialFloor") {
+        let properties = get_properties(object);
+        let time_trial_id = require_property(&properties, "time_trial", object)?;
+
+        time_trial_floors.entry(time_trial_id).or_default().push(import_floor(object, &properties, &map_info, 1.)?);
+    }
+
+    let mut sections = Vec::new();
+
+    for layer in map.descendants().filter(|node| node.has_tag_name("layer") || node.has_tag_name("objectgroup")) {
+        let properties = get_properties(layer);
+
+        let mut section = LevelSection {
+            progression: ProgressionRange { from: get_property(&properties, "from")?, until: get_property(&properties, "until")? },
+            ..default()
+        };
+
+        if layer.has_tag_name("layer") {
+            section.floors = import_tile_layer(layer, &map_info)?;
+        }
+        else {
+            for object in layer.children().filter(|node| node.has_tag_name("object")) {
+                import_object(object, &map_info, &mut time_trial_floors, &mut section)?;
+            }
+        }
+
+        sections.push(section);
+    }
+
+    Ok(LevelAsset {
+        bgm: properties.get("bgm").cloned(),
+        background,
+        sections
+    })
+}
+
+fn import_tile_layer(layer: Node, map_info: &MapInfo) -> Result<Vec<FloorInfo>, TiledLevelLoaderError> {
+    let width: usize = parse_value("width", layer.attribute("width").unwrap_or("0"))?;
+
+    let Some(data) = layer.children().find(|node| node.has_tag_name("data")) else {
+        return Ok(Vec::new());
+    };
+
+    if data.attribute("encoding") != Some("csv") {
+        return Err(TiledLevelLoaderError::Unsupported("tile layers have to use CSV encoding".to_string()));
+    }
+
+    let tiles = data.text()
+        .unwrap_or_default()
+        .split(',')
+        .map(|tile_id| parse_value::<u32>("tile", tile_id.trim()).map(|tile_id| tile_id & !TILE_FLIP_FLAGS))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Neighbouring tiles are merged into as few floors as possible, first along rows and then across rows with the same span.
+    let mut floors = Vec::new();
+    let mut open_rectangles: Vec<TileRectangle> = Vec::new();
+
+    for (row, row_tiles) in tiles.chunks(width.max(1)).enumerate() {
+        let mut row_rectangles: Vec<TileRectangle> = Vec::new();
+
+        for (column, tile_id) in row_tiles.iter().enumerate() {
+            if *tile_id == 0 {
+                continue;
+            }
+
+            let floor_asset = map_info.get_tileset(*tile_id);
+
+            match row_rectangles.last_mut() {
+                Some(last) if last.column + last.width == column && last.floor_asset == floor_asset => last.width += 1,
+                _ => row_rectangles.push(TileRectangle { column, row, width: 1, height: 1, floor_asset })
+            }
+        }
+
+        for rectangle in row_rectangles.iter_mut() {
+            let matching_rectangle = open_rectangles.iter().position(|open| {
+                open.column == rectangle.column && open.width == rectangle.width && open.floor_asset == rectangle.floor_asset
+            });
+
+            if let Some(index) = matching_rectangle {
+                let open = open_rectangles.swap_remove(index);
+
+                rectangle.row = open.row;
+                rectangle.height = open.height + 1;
+            }
+        }
+
+        floors.extend(open_rectangles.drain(..).map(|rectangle| rectangle.get_floor_info(map_info)));
+        open_rectangles = row_rectangles;
+    }
+
+    floors.extend(open_rectangles.drain(..).map(|rectangle| rectangle.get_floor_info(map_info)));
+
+    Ok(floors)
+}
+
+struct TileRectangle {
+    column: usize,
+    row: usize,
+    width: usize,
+    height: usize,
+    floor_asset: FloorAssetType
+}
+
+impl TileRectangle {
+    fn get_floor_info(&self, map_info: &MapInfo) -> FloorInfo {
+        let top_left = Vec2::new(self.column as f32, self.row as f32) * map_info.tile_size;
+        let size = Vec2::new(self.width as f32, self.height as f32) * map_info.tile_size;
+        let entity_info = map_info.get_entity_info(top_left, size, 1.);
+
+        FloorInfo { position: entity_info.position, size: entity_info.size, breakable_wall: None, floor_asset: self.floor_asset }
+    }
+}
+
+fn import_object(
+    object: Node,
+    map_info: &MapInfo,
+    time_trial_floors: &mut HashMap<u32, Vec<FloorInfo>>,
+    section: &mut LevelSection
+) -> Result<(), TiledLevelLoaderError> {
+    let properties = get_properties(object);
+
+    match get_object_class(object) {
+        "Floor" => {
+            let default_z = if properties.contains_key("breakable_wall") { 2. } else { 1. };
+
+            section.floors.push(import_floor(object, &properties, map_info, default_z)?);
+        },
+        "Transition" => {
+            section.transitions.push(TransitionCollider {
+                exit_index: require_property(&properties, "exit_index", object)?,
+                floor_info: get_object_entity_info(object, &properties, map_info, 2.)?,
+                safe_position: get_safe_position(object, &properties, map_info)?,
+                transition_to_level: require_property(&properties, "transition_to_level", object)?
+            });
+        },
+        "Door" => {
+            section.doors.push(DoorCollider {
+                floor_info: get_object_entity_info(object, &properties, map_info, 0.)?,
+                transition_to_level: require_property(&properties, "transition_to_level", object)?,
+                safe_position: get_safe_position(object, &properties, map_info)?,
+                is_active: false,
+                door_type: get_property(&properties, "door_type")?.unwrap_or_default()
+            });
+        },
+        "JumpPad" => {
+            section.floor_modifications.push(FloorModification::JumpPad(JumpPad {
+                floor_info: get_object_entity_info(object, &properties, map_info, 0.)?
+            }));
+        },
+        "GravityInverter" => {
+            section.floor_modifications.push(FloorModification::GravityInverter(GravityInverter {
+                floor_info: get_object_entity_info(object, &properties, map_info, 0.)?
+            }));
+        },
+        "TimeTrial" => {
+            let id = require_property(&properties, "id", object)?;
+
+            section.floor_modifications.push(FloorModification::TimeTrial(TimeTrial {
+                lever_info: get_object_entity_info(object, &properties, map_info, 0.)?,
+                floor_infos: time_trial_floors.remove(&id).unwrap_or_default(),
+                seconds_to_complete: require_property(&properties, "seconds_to_complete", object)?,
+                id,
+                is_active: false
+            }));
+        },
+        "IllusoryWall" => {
+            let entity_info = get_object_entity_info(object, &properties, map_info, 4.)?;
+
+            section.floor_modifications.push(FloorModification::IllusoryWall(IllusoryWall {
+                position: entity_info.position,
+                size: entity_info.size,
+                floor_asset: get_property(&properties, "floor_asset")?.unwrap_or(map_info.background)
+            }));
+        },
+        "Decoration" => {
+            let entity_info = get_object_entity_info(object, &properties, map_info, 1.)?;
+
+            section.floor_modifications.push(FloorModification::Decoration(Decoration {
+                position: entity_info.position,
+                size: entity_info.size,
+                asset: properties.get("asset").cloned().ok_or_else(|| TiledLevelLoaderError::MissingProperty("asset".to_string(), get_object_name(object)))?
+            }));
+        },
+        "Hazard" => {
+            section.floor_modifications.push(FloorModification::Hazard(Hazard {
+                floor_info: get_object_entity_info(object, &properties, map_info, 1.)?,
+                hazard_type: get_property(&properties, "hazard_type")?.unwrap_or(HazardType::from_background(map_info.background)),
+                damage: get_property(&properties, "damage")?
+            }));
+        },
+        "Checkpoint" => {
+            section.floor_modifications.push(FloorModification::Checkpoint(Checkpoint {
+                floor_info: get_object_entity_info(object, &properties, map_info, 0.)?,
+                is_active: false
+            }));
+        },
+        "MovingPlatform" => {
+            let floor_info = import_floor(object, &properties, map_info, 1.)?;
+            let waypoints: Vec<(f32, f32)> = require_property(&properties, "waypoints", object)?;
+
+            section.floor_modifications.push(FloorModification::MovingPlatform(MovingPlatform {
+                floor_info,
+                // Tiled's y axis points down.
+                waypoints: waypoints.into_iter().map(|(x, y)| Vec2::new(x, -y)).collect(),
+                speed: require_property(&properties, "speed", object)?,
+                path_mode: get_property(&properties, "path_mode")?.unwrap_or_default(),
+                lever_id: get_property(&properties, "lever")?,
+                is_running: false,
+                next_waypoint: 0,
+                is_reversed: false
+            }));
+        },
+        "PlatformLever" => {
+            section.floor_modifications.push(FloorModification::PlatformLever(PlatformLever {
+                lever_info: get_object_entity_info(object, &properties, map_info, 0.)?,
+                id: require_property(&properties, "id", object)?,
+                is_active: false,
+                is_pulled: false
+            }));
+        },
+        "CrumblingPlatform" => {
+            section.floor_modifications.push(FloorModification::CrumblingPlatform(CrumblingPlatform {
+                floor_info: import_floor(object, &properties, map_info, 1.)?,
+                crumble_secs: require_property(&properties, "crumble_secs", object)?,
+                respawn_secs: require_property(&properties, "respawn_secs", object)?,
+                state: CrumbleState::Solid,
+                time_passed: 0.
+            }));
+        },
+        "Npc" => {
+            section.npcs.push(NpcPlacement {
+                name: properties.get("npc").cloned().ok_or_else(|| TiledLevelLoaderError::MissingProperty("npc".to_string(), get_object_name(object)))?,
+                floor_info: get_object_entity_info(object, &properties, map_info, 2.)?,
+                progression: ProgressionRange { from: get_property(&properties, "from")?, until: get_property(&properties, "until")? }
+            });
+        },
+        // Already collected into their time trials.
+        "TimeTrialFloor" => {},
+        class => warn!("Skipping {} with unknown class `{}`", get_object_name(object), class)
+    }
+
+    Ok(())
+}
+
+fn import_floor(object: Node, properties: &HashMap<String, String>, map_info: &MapInfo, default_z: f32) -> Result<FloorInfo, TiledLevelLoaderError> {
+    let entity_info = get_object_entity_info(object, properties, map_info, default_z)?;
+
+    Ok(FloorInfo {
+        position: entity_info.position,
+        size: entity_info.size,
+        breakable_wall: get_property(properties, "breakable_wall")?.map(|index| BreakableWall { index }),
+        floor_asset: get_property(properties, "floor_asset")?.unwrap_or(map_info.background)
+    })
+}
+
+fn get_object_entity_info(object: Node, properties: &HashMap<String, String>, map_info: &MapInfo, default_z: f32) -> Result<EntityInfo, TiledLevelLoaderError> {
+    let top_left = Vec2::new(get_attribute(object, "x")?, get_attribute(object, "y")?);
+    let size = Vec2::new(get_attribute(object, "width")?, get_attribute(object, "height")?);
+    let z = get_property(properties, "z")?.unwrap_or(default_z);
+
+    Ok(map_info.get_entity_info(top_left, size, z))
+}
+
+fn get_safe_position(object: Node, properties: &HashMap<String, String>, map_info: &MapInfo) -> Result<Vec3, TiledLevelLoaderError> {
+    let safe_position = Vec2::new(require_property(properties, "safe_x", object)?, require_property(properties, "safe_y", object)?);
+
+    Ok(map_info.get_entity_info(safe_position, Vec2::ZERO, CWEAMPUFF_Z_INDEX).position)
+}
+
+// Tiled calls it `type` in older versions and `class` since 1.9.
+fn get_object_class<'a>(object: Node<'a, '_>) -> &'a str {
+    object.attribute("type").or_else(|| object.attribute("class")).unwrap_or_default()
+}
+
+fn get_object_name(object: Node) -> String {
+    format!("object {}", object.attribute("id").unwrap_or("?"))
+}
+
+fn get_attribute(node: Node, name: &str) -> Result<f32, TiledLevelLoaderError> {
+    node.attribute(name).map_or(Ok(0.), |value| parse_value(name, value))
+}
+
+fn get_properties(node: Node) -> HashMap<String, String> {
+    node.children()
+        .filter(|child| child.has_tag_name("properties"))
+        .flat_map(|properties| properties.children().filter(|property| property.has_tag_name("property")))
+        .filter_map(|property| {
+            // Multiline string properties keep their value in the element's text.
+            let value = property.attribute("value").or_else(|| property.text())?;
+
+            Some((property.attribute("name")?.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn get_property<T: DeserializeOwned>(properties: &HashMap<String, String>, name: &str) -> Result<Option<T>, TiledLevelLoaderError> {
+    properties.get(name).map(|value| parse_value(name, value)).transpose()
+}
+
+fn require_property<T: DeserializeOwned>(properties: &HashMap<String, String>, name: &str, object: Node) -> Result<T, TiledLevelLoaderError> {
+    get_property(properties, name)?.ok_or_else(|| TiledLevelLoaderError::MissingProperty(name.to_string(), get_object_name(object)))
+}
+
+// Numbers and enum variants like `Hell2` or `HasLetter` are both valid RON, so one parser covers every property type.
+fn parse_value<T: DeserializeOwned>(name: &str, value: &str) -> Result<T, TiledLevelLoaderError> {
+    ron::from_str(value).map_err(|_| TiledLevelLoaderError::InvalidValue(name.to_string(), value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::level::{progression::Progression, Level};
+
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../../tests/fixtures/tiled_import.tmx");
+
+    #[test]
+    fn imports_map_properties_and_one_section_per_layer() {
+        let level_asset = import_tiled_map(FIXTURE).unwrap();
+
+        assert_eq!(level_asset.bgm.as_deref(), Some("hell"));
+        assert!(level_asset.background == FloorAssetType::Hell);
+        assert_eq!(level_asset.sections.len(), 2);
+        assert!(level_asset.sections[0].progression.is_always());
+        assert_eq!(level_asset.sections[1].progression.from, Some(Progression::HasLetter));
+        assert_eq!(level_asset.sections[1].progression.until, None);
+    }
+
+    #[test]
+    fn merges_tiles_into_floors() {
+        let level_asset = import_tiled_map(FIXTURE).unwrap();
+        let floors = &level_asset.sections[0].floors;
+
+        assert_eq!(floors.len(), 2);
+
+        // Both tiles of the first tileset are Hell, so the 2x2 block becomes one floor.
+        assert_eq!(floors[0].position, Vec3::new(-100., 50., 1.));
+        assert_eq!(floors[0].size, Vec2::new(200., 200.));
+        assert!(floors[0].floor_asset == FloorAssetType::Hell);
+
+        // The external tileset is named after its file and the flipped tile in the last row still belongs to it.
+        assert_eq!(floors[1].position, Vec3::new(150., 0., 1.));
+        assert_eq!(floors[1].size, Vec2::new(100., 300.));
+        assert!(floors[1].floor_asset == FloorAssetType::Forest);
+    }
+
+    #[test]
+    fn converts_objects_to_level_coordinates() {
+        let level_asset = import_tiled_map(FIXTURE).unwrap();
+        let section = &level_asset.sections[1];
+
+        let transition = &section.transitions[0];
+        assert_eq!(transition.exit_index, 2);
+        assert_eq!(transition.floor_info.position, Vec3::new(-150., -100., 2.));
+        assert_eq!(transition.safe_position, Vec3::new(-50., -100., CWEAMPUFF_Z_INDEX));
+        assert_eq!(transition.transition_to_level, Level::Hell2);
+
+        let floor = &section.floors[0];
+        assert_eq!(floor.position, Vec3::new(-175., 125., 2.));
+        assert_eq!(floor.breakable_wall.map(|breakable_wall| breakable_wall.index), Some(1));
+        assert!(floor.floor_asset == FloorAssetType::Forest);
+    }
+
+    #[test]
+    fn imports_floor_modifications() {
+        let level_asset = import_tiled_map(FIXTURE).unwrap();
+        let floor_modifications = &level_asset.sections[1].floor_modifications;
+
+        let Some(FloorModification::Hazard(hazard)) = floor_modifications.first() else {
+            panic!("expected a hazard first");
+        };
+
+        assert_eq!(hazard.floor_info.position, Vec3::new(0., -125., 1.));
+        assert!(hazard.hazard_type == HazardType::Lava);
+        assert_eq!(hazard.damage, None);
+
+        let Some(FloorModification::MovingPlatform(moving_platform)) = floor_modifications.get(1) else {
+            panic!("expected a moving platform second");
+        };
+
+        assert_eq!(moving_platform.speed, 150.);
+        assert_eq!(moving_platform.waypoints, vec![Vec2::new(200., 0.), Vec2::new(200., -100.)]);
+        assert!(moving_platform.floor_info.floor_asset == FloorAssetType::Hell);
+    }
+
+    #[test]
+    fn rejects_malformed_xml() {
+        let result = import_tiled_map("<map><layer></map>");
+
+        assert!(matches!(result, Err(TiledLevelLoaderError::Xml(_))));
+    }
+
+    #[test]
+    fn rejects_infinite_maps() {
+        let result = import_tiled_map(&FIXTURE.replace(r#"infinite="0""#, r#"infinite="1""#));
+
+        assert!(matches!(result, Err(TiledLevelLoaderError::Unsupported(_))));
+    }
+
+    #[test]
+    fn rejects_tile_layers_that_are_not_csv() {
+        let result = import_tiled_map(&FIXTURE.replace(r#"encoding="csv""#, r#"encoding="base64""#));
+
+        assert!(matches!(result, Err(TiledLevelLoaderError::Unsupported(_))));
+    }
+
+    #[test]
+    fn rejects_objects_missing_required_properties() {
+        let result = import_tiled_map(&FIXTURE.replace(r#"<property name="exit_index" type="int" value="2"/>"#, ""));
+
+        assert!(matches!(result, Err(TiledLevelLoaderError::MissingProperty(name, object)) if name == "exit_index" && object == "object 1"));
+    }
+
+    #[test]
+    fn rejects_invalid_property_values() {
+        let result = import_tiled_map(&FIXTURE.replace(r#"value="Hell2""#, r#"value="Hell9""#));
+
+        assert!(matches!(result, Err(TiledLevelLoaderError::InvalidValue(name, value)) if name == "transition_to_level" && value == "Hell9"));
+    }
+
+    #[test]
+    fn rejects_invalid_tiles() {
+        let result = import_tiled_map(&FIXTURE.replace("1,2,0,3,", "1,2,x,3,"));
+
+        assert!(matches!(result, Err(TiledLevelLoaderError::InvalidValue(name, _)) if name == "tile"));
+    }
+}
@@ -0,0 +1,204 @@
+use bevy::{color::Color, prelude::*};
+
+use crate::{app_states::AppState, fade_in_fade_out::FadeInFadeOutNode, main_menu::{NewGame, DEFAULT_FONT, NORMAL_BUTTON}, save::{read_save_data, LoadGameEvent, SAVE_SLOTS}};
+use crate::localization::{Localization, LocalizedText};
+
+const SLOTS_TOP: f32 = 30.;
+const SLOT_SPACING: f32 = 12.5;
+
+// The slot list is also used to pick which save a new game replaces once every slot is taken.
+#[derive(Resource, Default, PartialEq)]
+pub enum LoadMenuMode {
+    #[default]
+    Load,
+    Overwrite
+}
+
+#[derive(Component)]
+pub enum LoadMenuButtonAction {
+    LoadSlot(u32),
+    OverwriteSlot(u32),
+    Back
+}
+
+#[derive(Component)]
+pub struct LoadMenuComponent;
+
+type LoadMenuButtonInteraction<'a> = (&'a Interaction, &'a LoadMenuButtonAction);
+
+pub fn load_menu_button_interactions_handler(
+    mut interaction_query: Query<LoadMenuButtonInteraction, (Changed<Interaction>, With<Button>)>,
+    mut app_state: ResMut<NextState<AppState>>,
+    mut load_game: EventWriter<LoadGameEvent>,
+    mut new_game: NewGame,
+) {
+    for (interaction, action) in &mut interaction_query {
+        if let Interaction::Pressed = *interaction  {
+            match action {
+                LoadMenuButtonAction::LoadSlot(slot) => {
+                    load_game.write(LoadGameEvent(*slot));
+                },
+                LoadMenuButtonAction::OverwriteSlot(slot) => {
+                    new_game.start(*slot);
+                },
+                LoadMenuButtonAction::Back => {
+                    app_state.set(AppState::MainMenu);
+                },
+            };
+        }
+    }
+}
+
+pub fn spawn_load_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    localization: Localization,
+    load_menu_mode: Res<LoadMenuMode>,
+) {
+    let (title, slot_action): (_, fn(u32) -> LoadMenuButtonAction) = match *load_menu_mode {
+        LoadMenuMode::Load => ("load_menu.title", LoadMenuButtonAction::LoadSlot),
+        LoadMenuMode::Overwrite => ("load_menu.overwrite_title", LoadMenuButtonAction::OverwriteSlot)
+    };
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::Srgba(Srgba { red: 0.1, green: 0.1, blue: 0.1, alpha: 0. })),
+        LoadMenuComponent
+    )).with_children(|main_parent| {
+    main_parent
+        .spawn((Node {
+            width: Val::Percent(30.0),
+            height: Val::Percent(20.0),
+            top: Val::Percent(10.),
+            left: Val::Percent(35.),
+            position_type: PositionType::Absolute,
+            justify_content: JustifyContent::Center,
+            ..default()
+        }, LoadMenuComponent))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    LocalizedText(title),
+                    TextShadow {
+                        offset: Vec2::splat(2.),
+                        color: Color::linear_rgba(0., 0., 0., 1.),
+                    },
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 40.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+        });
+
+    for slot in 0..SAVE_SLOTS {
+        let slot_node = Node {
+            width: Val::Percent(50.0),
+            height: Val::Percent(10.0),
+            top: Val::Percent(SLOTS_TOP + slot as f32 * SLOT_SPACING),
+            left: Val::Percent(25.),
+            position_type: PositionType::Absolute,
+            border: UiRect::all(Val::Px(5.0)),
+            // horizontally center child text
+            justify_content: JustifyContent::Center,
+            // vertically center child text
+            align_items: AlignItems::Center,
+            ..default()
+        };
+
+        let slot_text = (
+            TextFont {
+                font: asset_server.load(DEFAULT_FONT),
+                font_size: 33.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+        );
+
+        match read_save_data(slot) {
+            Some(save_data) => {
+                main_parent
+                    .spawn((
+                        Button,
+                        slot_action(slot),
+                        slot_node,
+                        BorderColor(Color::BLACK),
+                        BorderRadius::all(Val::Px(20.)),
+                        BackgroundColor(NORMAL_BUTTON),
+                    ))
+                    .with_child((
+                        Text::new(localization.get("load_menu.slot")
+                            .replace("{slot}", &(slot + 1).to_string())
+                            .replace("{level}", &localization.get(&save_data.level.get_localization_id()))
+                            .replace("{progression}", &localization.get(save_data.cweampuff.progression.get_localization_id()))
+                            .replace("{play_time}", &save_data.get_play_time_label())),
+                        slot_text,
+                    ));
+            },
+            None => {
+                main_parent
+                    .spawn((
+                        slot_node,
+                        BorderColor(Color::BLACK),
+                        BorderRadius::all(Val::Px(20.)),
+                        BackgroundColor(NORMAL_BUTTON.with_alpha(0.3)),
+                    ))
+                    .with_child((
+                        Text::new(localization.get("load_menu.empty_slot").replace("{slot}", &(slot + 1).to_string())),
+                        slot_text,
+                    ));
+            }
+        }
+    }
+
+    main_parent
+        .spawn((
+            Button,
+            LoadMenuComponent,
+            LoadMenuButtonAction::Back,
+            Node {
+                width: Val::Percent(10.0),
+                height: Val::Percent(5.0),
+                top: Val::Percent(80.),
+                left: Val::Percent(45.),
+                position_type: PositionType::Absolute,
+                border: UiRect::all(Val::Px(5.0)),
+                // horizontally center child text
+                justify_content: JustifyContent::Center,
+                // vertically center child text
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BorderColor(Color::BLACK),
+            BorderRadius::MAX,
+            BackgroundColor(NORMAL_BUTTON),
+        ))
+        .with_child((
+            LocalizedText("menu.back"),
+            TextFont {
+                font: asset_server.load(DEFAULT_FONT),
+                font_size: 33.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+        ));
+    });
+
+}
+
+type LoadMenuNodeFilter = (With<Node>, With<LoadMenuComponent>, Without<Camera2d>, Without<FadeInFadeOutNode>);
+
+pub fn despawn_load_menu(
+    mut commands: Commands,
+    query: Query<Entity, LoadMenuNodeFilter>
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -0,0 +1,204 @@
+use std::{collections::HashMap, fmt::Display};
+
+use bevy::{asset::{io::Reader, AssetLoader, LoadContext}, ecs::system::SystemParam, prelude::*};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::main_menu::DEFAULT_FONT;
+
+#[derive(Clone, Copy, Hash, Eq, PartialEq, Default, Debug, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [
+        Language::English,
+        Language::Spanish
+    ];
+
+    pub fn get_next(&self) -> Language {
+        let index = Language::ALL.iter().position(|language| language == self).unwrap_or_default();
+
+        Language::ALL[(index + 1) % Language::ALL.len()]
+    }
+
+    fn get_asset_path(&self) -> String {
+        let file_name = match self {
+            Language::English => "english",
+            Language::Spanish => "spanish",
+        };
+
+        format!("localization/{}.strings.ron", file_name)
+    }
+}
+
+// Languages are always listed in their own language, so they can be found by players who can't read the current one.
+impl Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+        };
+
+        write!(f, "{}", label)
+    }
+}
+
+#[derive(Resource, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LocalizationSettings {
+    pub language: Language
+}
+
+// Text that is looked up in the string table of the current language, and updated whenever the language changes.
+#[derive(Component)]
+#[require(Text)]
+pub struct LocalizedText(pub &'static str);
+
+// Texts that pick their own font, so the language's regular font isn't swapped in for it.
+#[derive(Component)]
+pub struct CustomFont;
+
+// The fonts are optional, a language only needs to name one when the default font doesn't cover its script.
+#[derive(Asset, TypePath, Serialize, Deserialize)]
+pub struct StringTable {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bold_font: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub italic_font: Option<String>,
+    pub strings: HashMap<String, String>,
+}
+
+#[derive(Resource)]
+pub struct StringTables {
+    tables: HashMap<Language, Handle<StringTable>>,
+}
+
+pub fn load_string_tables(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>
+) {
+    let tables = Language::ALL
+        .into_iter()
+        .map(|language| (language, asset_server.load(language.get_asset_path())))
+        .collect();
+
+    commands.insert_resource(StringTables { tables });
+}
+
+// Menus and prompts refer to their text by id, every id has to be in the English string table.
+// Dialogue and cutscenes carry their English text inline, so their ids only show up in the other languages' tables.
+#[derive(SystemParam)]
+pub struct Localization<'w> {
+    settings: Res<'w, LocalizationSettings>,
+    string_tables: Res<'w, StringTables>,
+    string_table_assets: Res<'w, Assets<StringTable>>,
+}
+
+impl Localization<'_> {
+    pub fn get(&self, id: &str) -> String {
+        if let Some(text) = self.get_from_table(self.settings.language, id) {
+            return text.to_string();
+        }
+
+        match self.get_table(Language::English) {
+            Some(english_table) => english_table.strings.get(id).cloned().unwrap_or_else(|| id.to_string()),
+            // Nothing is shown until the string tables finish loading.
+            None => String::new()
+        }
+    }
+
+    pub fn get_or(&self, id: Option<&str>, english_text: &str) -> String {
+        if self.settings.language == Language::English {
+            return english_text.to_string();
+        }
+
+        id.and_then(|id| self.get_from_table(self.settings.language, id))
+            .unwrap_or(english_text)
+            .to_string()
+    }
+
+    pub fn get_font_path(&self) -> &str {
+        self.get_table(self.settings.language)
+            .and_then(|table| table.font.as_deref())
+            .unwrap_or(DEFAULT_FONT)
+    }
+
+    pub fn get_bold_font_path(&self) -> Option<&str> {
+        self.get_table(self.settings.language).and_then(|table| table.bold_font.as_deref())
+    }
+
+    pub fn get_italic_font_path(&self) -> Option<&str> {
+        self.get_table(self.settings.language).and_then(|table| table.italic_font.as_deref())
+    }
+
+    fn get_table(&self, language: Language) -> Option<&StringTable> {
+        self.string_tables.tables.get(&language).and_then(|handle| self.string_table_assets.get(handle))
+    }
+
+    fn get_from_table(&self, language: Language, id: &str) -> Option<&str> {
+        self.get_table(language).and_then(|table| table.strings.get(id)).map(String::as_str)
+    }
+}
+
+type LocalizedTextQuery<'a> = (&'a mut TextFont, Option<Ref<'a, LocalizedText>>, Option<&'a mut Text>);
+
+// Every text is spawned with the default font, which gets swapped for the current language's font here.
+pub fn update_localized_texts(
+    localization: Localization,
+    asset_server: Res<AssetServer>,
+    mut string_table_events: EventReader<AssetEvent<StringTable>>,
+    mut texts: Query<LocalizedTextQuery, Without<CustomFont>>,
+) {
+    let string_tables_changed = string_table_events.read().count() > 0;
+    let update_all = string_tables_changed || localization.settings.is_changed();
+
+    let font = asset_server.load(localization.get_font_path());
+
+    for (mut text_font, localized_text, text) in texts.iter_mut() {
+        if !update_all && !text_font.is_added() && !localized_text.as_ref().is_some_and(|localized_text| localized_text.is_changed()) {
+            continue;
+        }
+
+        if text_font.font != font {
+            text_font.font = font.clone();
+        }
+
+        if let (Some(localized_text), Some(mut text)) = (localized_text, text) {
+            **text = localization.get(localized_text.0);
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct StringTableLoader;
+
+#[derive(Debug, Error)]
+pub enum StringTableLoaderError {
+    #[error("Could not read string table: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse string table: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for StringTableLoader {
+    type Asset = StringTable;
+    type Settings = ();
+    type Error = StringTableLoaderError;
+
+    async fn load(&self, reader: &mut dyn Reader, _settings: &(), _load_context: &mut LoadContext<'_>) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        Ok(ron::de::from_bytes::<StringTable>(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["strings.ron"]
+    }
+}
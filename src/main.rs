@@ -11,28 +11,51 @@ mod npc;
 mod fade_in_fade_out;
 mod audio_settings;
 mod audio_settings_menu;
+mod dialogue_settings;
 mod animations;
 mod credits_menu;
 mod asset_loader;
+mod save;
+mod load_menu;
+mod settings;
+mod controls;
+mod controls_menu;
+mod backlog_menu;
+mod menu_navigation;
+mod localization;
+mod death;
+mod health;
 
 use std::sync::atomic::AtomicBool;
 use app_states::AppState;
 use audio_settings::AudioSettings;
 use audio_settings_menu::{audio_button_interactions_handler, despawn_audio_settings, settings_menu_input_reader, spawn_audio_menu};
-use bevy::{log::LogPlugin, prelude::*, window::{PrimaryWindow, WindowMode}, winit::WinitWindows};
+use bevy::{input::InputSystem, log::LogPlugin, prelude::*, ui::UiSystem, window::{PrimaryWindow, WindowMode}, winit::WinitWindows};
 use bevy_rapier2d::{plugin::{NoUserData, RapierPhysicsPlugin}, prelude::{Collider, Friction, GravityScale, LockedAxes, RigidBody, Velocity}};
 use camera::{cweampuff_camera_adjustment, spawn_camera};
+use death::{death_animation, respawn_cweampuff, start_dying, update_respawn_point, DeathState, RespawnPoint};
+use health::{damage_event_reader, despawn_health_hud, end_invulnerability, invulnerability_monitor, spawn_health_hud, update_health_hud, DamageEvent, Health};
+use dialogue_settings::DialogueSettings;
 use credits_menu::{credits_button_interactions_handler, despawn_credits_menu, spawn_credits_menu};
 use cutscene::{cutscene_event_reader, cutscene_input_reader, cutscene_player, despawn_cutscene_resources, spawn_cutscene_resources, wait_for_resources_to_load, CutsceneEvent};
 use fade_in_fade_out::{despawn_fade_in_fade_out_node, fade_in, fade_out, set_fade_in_state, set_fade_out_state, spawn_fade_in_fade_out_node, FadeState};
 use interactable::{despawn_interaction_prompt, interaction_state::InteractionState, spawn_interaction_prompt};
-use level::{cheats::cheat_transition_to, despawn_current_level, door::{door_start_interaction_input_reader, interactable_door_collision_reader}, floor_modification::{gravity_inverter_collision_reader, jump_pad_collision_reader, tick_timer_trial_timer, time_trial_collision_reader, time_trial_start_interaction_input_reader}, level_bgm::{fade_in_bgm, fade_out_bgm, set_bgm_state, LevelBGMState}, level_layout::FloorCollider, level_transition_collision_reader, progression::Progression, spawn_new_level, transition_states::TransitionState};
+use level::{cheats::cheat_transition_to, despawn_current_level, door::{door_start_interaction_input_reader, interactable_door_collision_reader}, floor_modification::{checkpoint_collision_reader, checkpoint_start_interaction_input_reader, crumbling_platform_monitor, gravity_inverter_collision_reader, hazard_collision_reader, jump_pad_collision_reader, move_platforms, platform_lever_collision_reader, platform_lever_start_interaction_input_reader, tick_timer_trial_timer, time_trial_collision_reader, time_trial_start_interaction_input_reader}, level_bgm::{fade_in_bgm, fade_out_bgm, set_bgm_state, LevelBGMState}, level_asset::{LevelAsset, LevelAssetLoader}, editor::{despawn_editor, EditorMode, draw_editor_gizmos, editor_camera_movement, editor_keyboard_input, editor_level_changed_event_reader, editor_mouse_input, editor_toggle_input_reader, spawn_editor, update_editor_status_text, EditorLevelChangedEvent}, level_layout::FloorCollider, level_hot_reload, level_transition_collision_reader, load_levels, progression::Progression, spawn_new_level, spawn_pending_level, tiled_import::TiledLevelLoader, PendingLevel, transition_states::TransitionState};
 use main_menu::{button_visuals_handler, despawn_background, despawn_main_menu, main_menu_button_interactions_handler, spawn_background_image, spawn_main_menu};
 use movement::*;
 use animations::play_animations;
-use npc::{conversation_input_reader, conversation_state::ConversationState, despawn_conversation_resources, dialog_box_text_writer, dialog_state::DialogState, left_character_talking, npc_collision_reader, npc_start_interaction_input_reader, right_character_talking, spawn_conversation_resources};
+use npc::{character_talking, conversation_action::{conversation_action_event_reader, ConversationActionEvent}, conversation_choice_handler, conversation_input_reader, conversation_skip_input_reader, conversation_state::ConversationState, despawn_conversation_resources, dialog_box_line_starter, dialog_box_span_updater, dialog_box_text_writer, dialogue_asset::{load_dialogues, DialogueAsset, DialogueAssetLoader}, dialogue_markup::animate_text_effects, dialog_state::DialogState, npc_collision_reader, npc_start_interaction_input_reader, spawn_conversation_resources, ConversationBranch, ConversationSkip, DialogueFlags, ReadDialogue};
 use winit::window::Icon;
 use asset_loader::load_asset;
+use save::{autosave, load_game_event_reader, save_game_event_reader, tick_play_time, CurrentSaveSlot, LoadGameEvent, LoadedSave, PlayTime, SaveGameEvent};
+use load_menu::{despawn_load_menu, load_menu_button_interactions_handler, spawn_load_menu, LoadMenuMode};
+use controls::{update_action_input, ControlBindings, GameAction};
+use menu_navigation::{focus_visuals_handler, menu_focus_navigation};
+use backlog_menu::{backlog_button_interactions_handler, backlog_input_reader, backlog_open_input_reader, despawn_backlog_menu, spawn_backlog_menu, DialogueBacklog};
+use controls_menu::{controls_button_interactions_handler, controls_rebind_input_reader, despawn_controls_menu, spawn_controls_menu, PendingRebind};
+use localization::{load_string_tables, update_localized_texts, LocalizationSettings, StringTable, StringTableLoader};
+use settings::{load_settings, settings_changed_event_reader, SettingsChangedEvent};
+use serde::{Deserialize, Serialize};
 
 use crate::level::cheats::programmer_art_cheats;
 
@@ -43,6 +66,8 @@ const CWEAMPUFF_JUMP_IMPULSE: f32 = 800.;
 const CWEAMPUFF_DASH_IMPULSE: f32 = 650.;
 pub const CWEAMPUFF_DIAMETER: f32 = 30.;
 pub const CWEAMPUFF_GRAVITY_SCALE: f32 = 1.5;
+pub const CWEAMPUFF_SPRITE_SIZE: Vec2 = Vec2::new(2.0, 1.5);
+const CWEAMPUFF_MAX_HEALTH: u32 = 5;
 
 pub static USE_PROGRAMMER_ART: AtomicBool = AtomicBool::new(false);
 
@@ -65,12 +90,53 @@ fn main() {
     app.init_state::<FadeState>();
     app.init_state::<DialogState>();
     app.init_state::<LevelBGMState>();
+    app.init_state::<DeathState>();
+    app.add_sub_state::<EditorMode>();
+
+    app.init_asset::<LevelAsset>();
+    app.init_asset_loader::<LevelAssetLoader>();
+    app.init_asset::<DialogueAsset>();
+    app.init_asset_loader::<DialogueAssetLoader>();
+    app.init_asset_loader::<TiledLevelLoader>();
+    app.init_asset::<StringTable>();
+    app.init_asset_loader::<StringTableLoader>();
 
     app.add_event::<CutsceneEvent>();
+    app.add_event::<SaveGameEvent>();
+    app.add_event::<LoadGameEvent>();
+    app.add_event::<SettingsChangedEvent>();
+    app.add_event::<EditorLevelChangedEvent>();
+    app.add_event::<ConversationActionEvent>();
+    app.add_event::<DamageEvent>();
 
     app.init_resource::<AudioSettings>();
+    app.init_resource::<CurrentSaveSlot>();
+    app.init_resource::<PlayTime>();
+    app.init_resource::<DialogueFlags>();
+    app.init_resource::<ReadDialogue>();
+    app.init_resource::<ConversationBranch>();
+    app.init_resource::<ConversationSkip>();
+    app.init_resource::<DialogueBacklog>();
+    app.init_resource::<RespawnPoint>();
+    app.init_resource::<LoadMenuMode>();
+    app.init_resource::<ControlBindings>();
+    app.init_resource::<LocalizationSettings>();
+    app.init_resource::<DialogueSettings>();
+    app.init_resource::<ButtonInput<GameAction>>();
+    app.init_resource::<PendingRebind>();
+
+    app.add_systems(Startup, (set_window_icon, spawn_camera, setup_window, load_settings, load_string_tables, load_levels, load_dialogues))
+
+    // INPUT SYSTEMS
+        .add_systems(PreUpdate, update_action_input.after(InputSystem))
+        .add_systems(PreUpdate, menu_focus_navigation.after(UiSystem::Focus).after(update_action_input))
+        .add_systems(Update, focus_visuals_handler)
+
+    // SETTINGS SYSTEMS
+        .add_systems(Update, settings_changed_event_reader)
 
-    app.add_systems(Startup, (set_window_icon, spawn_camera, setup_window))
+    // LOCALIZATION SYSTEMS
+        .add_systems(PostUpdate, update_localized_texts.before(UiSystem::Prepare))
 
     // MAIN MENU SYSTEMS
         .add_systems(OnEnter(AppState::MainMenu), (despawn_current_level, despawn_cweampuff, spawn_background_image, spawn_main_menu).chain())
@@ -99,6 +165,31 @@ fn main() {
         ).run_if(in_state(AppState::CreditsMenu)))
         .add_systems(OnExit(AppState::CreditsMenu), despawn_credits_menu)
 
+    // LOAD MENU SYSTEMS
+        .add_systems(OnEnter(AppState::LoadMenu), spawn_load_menu)
+        .add_systems(Update, (
+            button_visuals_handler,
+            load_menu_button_interactions_handler
+        ).run_if(in_state(AppState::LoadMenu)))
+        .add_systems(OnExit(AppState::LoadMenu), despawn_load_menu)
+
+    // CONTROLS MENU SYSTEMS
+        .add_systems(OnEnter(AppState::ControlsMenu), spawn_controls_menu)
+        .add_systems(Update, (
+            button_visuals_handler,
+            (controls_rebind_input_reader, controls_button_interactions_handler).chain()
+        ).run_if(in_state(AppState::ControlsMenu)))
+        .add_systems(OnExit(AppState::ControlsMenu), despawn_controls_menu)
+
+    // BACKLOG MENU SYSTEMS
+        .add_systems(OnEnter(AppState::BacklogMenu), spawn_backlog_menu)
+        .add_systems(Update, (
+            button_visuals_handler,
+            backlog_button_interactions_handler,
+            backlog_input_reader
+        ).run_if(in_state(AppState::BacklogMenu)))
+        .add_systems(OnExit(AppState::BacklogMenu), despawn_backlog_menu)
+
     // CUTSCENE SYSTEMS
         .add_systems(OnEnter(AppState::Cutscene), spawn_cutscene_resources)
         .add_systems(Update, cutscene_event_reader)
@@ -119,22 +210,52 @@ fn main() {
         .add_systems(FixedUpdate, fade_in_bgm.run_if(in_state(LevelBGMState::Changing)).run_if(in_state(FadeState::FadeIn)))
         .add_systems(FixedUpdate, fade_out_bgm.run_if(in_state(LevelBGMState::Changing)).run_if(in_state(TransitionState::Finished)).run_if(in_state(AppState::InGame)))
         .add_systems(OnEnter(TransitionState::Finished), (set_fade_out_state, reset_abilities).chain())
-        .add_systems(OnEnter(FadeState::FadeInFinished), (despawn_current_level, spawn_new_level).run_if(in_state(TransitionState::Started)).chain())
+        // The new level is swapped in once the screen is covered and the level it goes to has finished loading.
+        .add_systems(Update, (
+            spawn_pending_level.run_if(resource_exists::<PendingLevel>),
+            (despawn_current_level, spawn_new_level).chain().run_if(not(resource_exists::<PendingLevel>))
+        ).chain().run_if(in_state(FadeState::FadeInFinished)).run_if(in_state(TransitionState::Started)))
+        .add_systems(Update, level_hot_reload.run_if(in_state(AppState::InGame)).run_if(in_state(EditorMode::Off)).run_if(in_state(TransitionState::Finished)))
+
+    // SAVE SYSTEMS
+        .add_systems(OnEnter(TransitionState::Finished), autosave.run_if(in_state(AppState::InGame)))
+        .add_systems(Update, (save_game_event_reader, load_game_event_reader))
+        .add_systems(Update, tick_play_time.run_if(in_state(AppState::InGame)).run_if(in_state(EditorMode::Off)))
 
     // INTERACTION SYSTEMS
         .add_systems(OnEnter(InteractionState::Ready), spawn_interaction_prompt)
         .add_systems(Update, (
             npc_start_interaction_input_reader, 
-            time_trial_start_interaction_input_reader
-        ).run_if(in_state(InteractionState::Ready)))
+            time_trial_start_interaction_input_reader,
+            checkpoint_start_interaction_input_reader,
+            platform_lever_start_interaction_input_reader
+        ).run_if(in_state(InteractionState::Ready)).run_if(in_state(DeathState::Alive)))
         .add_systems(OnExit(InteractionState::Ready), despawn_interaction_prompt)
         .add_systems(OnEnter(ConversationState::Started), (spawn_conversation_resources, kill_momentum))
-        .add_systems(Update,conversation_input_reader.run_if(in_state(ConversationState::Started)))
-        .add_systems(FixedUpdate, left_character_talking.run_if(in_state(ConversationState::Started)).run_if(in_state(DialogState::LeftCharacterTalking)))
-        .add_systems(FixedUpdate, right_character_talking.run_if(in_state(ConversationState::Started)).run_if(in_state(DialogState::RightCharacterTalking)))
-        .add_systems(FixedUpdate, dialog_box_text_writer.run_if(in_state(ConversationState::Started)))
+        .add_systems(Update, (
+            button_visuals_handler,
+            (conversation_choice_handler, conversation_skip_input_reader, conversation_input_reader).chain(),
+            backlog_open_input_reader
+        ).run_if(in_state(ConversationState::Started)).run_if(in_state(AppState::InGame)))
+        .add_systems(Update, conversation_action_event_reader.after(conversation_input_reader).run_if(in_state(AppState::InGame)))
+        .add_systems(FixedUpdate, character_talking.run_if(in_state(ConversationState::Started)))
+        .add_systems(FixedUpdate, (dialog_box_line_starter, dialog_box_text_writer, dialog_box_span_updater).chain().run_if(in_state(ConversationState::Started)).run_if(in_state(AppState::InGame)))
+        .add_systems(PostUpdate, animate_text_effects.after(UiSystem::PostLayout))
         .add_systems(OnExit(ConversationState::Started), despawn_conversation_resources)
 
+    // EDITOR SYSTEMS
+        .add_systems(OnEnter(EditorMode::On), spawn_editor)
+        .add_systems(Update, (
+            editor_toggle_input_reader,
+            editor_camera_movement,
+            editor_mouse_input,
+            editor_keyboard_input,
+            editor_level_changed_event_reader,
+            draw_editor_gizmos,
+            update_editor_status_text
+        ).chain().run_if(in_state(EditorMode::On)))
+        .add_systems(OnExit(EditorMode::On), despawn_editor)
+
     // GAMEPLAY SYSTEMS
         .add_systems(OnEnter(AppState::InGame), (despawn_background, spawn_cweampuff))
         .add_systems(Update, (
@@ -145,8 +266,9 @@ fn main() {
             door_start_interaction_input_reader.run_if(in_state(InteractionState::Ready)),
             level_transition_collision_reader,
             cheat_transition_to,
-            programmer_art_cheats
-        ).chain().run_if(in_state(AppState::InGame)).run_if(in_state(TransitionState::Finished)).run_if(in_state(ConversationState::Finished)).run_if(in_state(FadeState::None)))
+            programmer_art_cheats,
+            editor_toggle_input_reader
+        ).chain().run_if(in_state(AppState::InGame)).run_if(in_state(EditorMode::Off)).run_if(in_state(TransitionState::Finished)).run_if(in_state(ConversationState::Finished)).run_if(in_state(FadeState::None)).run_if(in_state(DeathState::Alive)))
         .add_systems(FixedUpdate, (
             dash_reset,
             jump_reset,
@@ -161,8 +283,30 @@ fn main() {
             time_trial_collision_reader,
             tick_timer_trial_timer,
             settings_menu_input_reader,
+            hazard_collision_reader,
+            checkpoint_collision_reader,
+            damage_event_reader,
+            invulnerability_monitor,
             play_animations
-        ).run_if(in_state(AppState::InGame)).run_if(in_state(TransitionState::Finished)).run_if(in_state(ConversationState::Finished)).run_if(in_state(FadeState::None)))
+        ).run_if(in_state(AppState::InGame)).run_if(in_state(EditorMode::Off)).run_if(in_state(TransitionState::Finished)).run_if(in_state(ConversationState::Finished)).run_if(in_state(FadeState::None)).run_if(in_state(DeathState::Alive)))
+
+    // PLATFORM SYSTEMS
+        .add_systems(FixedUpdate, (
+            move_platforms,
+            platform_lever_collision_reader,
+            crumbling_platform_monitor
+        ).run_if(in_state(AppState::InGame)).run_if(in_state(EditorMode::Off)).run_if(in_state(TransitionState::Finished)).run_if(in_state(ConversationState::Finished)).run_if(in_state(FadeState::None)).run_if(in_state(DeathState::Alive)))
+
+    // DEATH SYSTEMS
+        .add_systems(OnEnter(TransitionState::Finished), update_respawn_point.run_if(in_state(AppState::InGame)))
+        .add_systems(OnEnter(DeathState::Dying), (start_dying, end_invulnerability))
+        .add_systems(FixedUpdate, death_animation.run_if(in_state(DeathState::Dying)).run_if(in_state(FadeState::None)))
+        .add_systems(OnEnter(FadeState::FadeInFinished), (respawn_cweampuff, reset_abilities).chain().run_if(in_state(DeathState::Dying)))
+
+    // HEALTH SYSTEMS
+        .add_systems(OnEnter(AppState::InGame), spawn_health_hud)
+        .add_systems(Update, update_health_hud.run_if(in_state(AppState::InGame)))
+        .add_systems(OnExit(AppState::InGame), despawn_health_hud)
         .run();
 }
 
@@ -202,21 +346,30 @@ fn spawn_cweampuff(
     mut commands: Commands,
     cweampuff_query: Query<&Cweampuff, With<Cweampuff>>,
     asset_server: Res<AssetServer>,
+    loaded_save: Option<Res<LoadedSave>>,
 ) {
     if !cweampuff_query.is_empty() {
         return;
     }
 
+    let cweampuff = match loaded_save {
+        Some(loaded_save) => {
+            commands.remove_resource::<LoadedSave>();
+            loaded_save.0.cweampuff
+        },
+        None => Cweampuff { progression: Progression::None, has_double_jump: false, has_wall_jump: false, has_dash: false }
+    };
+
     let cweampuff_model_handle = load_asset(&asset_server,"npcs/cweampuff/Model.png");
     
     // Cweampuff
     commands.spawn((
         RigidBody::Dynamic,
         Transform::from_translation(CWEAMPUFF_STARTING_POSITION).with_scale(Vec2::splat(CWEAMPUFF_DIAMETER).extend(CWEAMPUFF_Z_INDEX)),
-        Cweampuff { progression: Progression::None, has_double_jump: false, has_wall_jump: false, has_dash: false },
+        cweampuff,
         Sprite {
             image: cweampuff_model_handle,
-            custom_size: Some(Vec2::new(2.0, 1.5)),
+            custom_size: Some(CWEAMPUFF_SPRITE_SIZE),
             anchor: bevy::sprite::Anchor::Custom(Vec2::new(0., -0.1)),
             image_mode: SpriteImageMode::Auto,
             ..default()
@@ -232,6 +385,7 @@ fn spawn_cweampuff(
         Dasher { is_dash_available: false, dash_impulse: CWEAMPUFF_DASH_IMPULSE, dash_cooldown: 0.5, time_passed_since_dash: 0. },
         LockedAxes::ROTATION_LOCKED,
         Movable { is_upside_down: false, touching_ground: false, facing_right: true, hugging_left_wall: false, hugging_right_wall: false, is_stunlocked: false, stun_duration: 0.2, time_passed_since_stun: 0. },
+        Health::new(CWEAMPUFF_MAX_HEALTH),
     ));
 }
 
@@ -244,7 +398,7 @@ fn despawn_cweampuff(
     }
 }
 
-#[derive(Component, Clone, Copy)]
+#[derive(Component, Clone, Copy, Serialize, Deserialize)]
 struct Cweampuff {
     progression: Progression,
     has_double_jump: bool,
@@ -1,14 +1,21 @@
-use bevy::{audio::{PlaybackMode, Volume}, color::Color, prelude::*, ui::widget::NodeImageMode};
+use bevy::{audio::{PlaybackMode, Volume}, color::Color, ecs::system::SystemParam, prelude::*, ui::widget::NodeImageMode};
 
 use crate::{app_states::AppState, cutscene::{CutsceneEvent, CutsceneInfo, PostCutsceneAction}, fade_in_fade_out::FadeInFadeOutNode, level::{level_bgm::{LevelBGM, LevelBGMState}, Level}};
 use crate::asset_loader::load_asset;
+use crate::load_menu::LoadMenuMode;
+use crate::localization::{Localization, LocalizedText};
+use crate::menu_navigation::Focused;
+use crate::save::{get_most_recent_save_slot, get_new_game_slot, LoadGameEvent, SlotProgress};
 
 pub const DEFAULT_FONT: &str = "fonts/Shadows Into Light.ttf";
 
 pub const NORMAL_BUTTON: Color = Color::srgba(0.15, 0.15, 0.15, 0.75);
-const HOVERED_BUTTON: Color = Color::srgba(0.25, 0.25, 0.25, 0.75);
+pub const HOVERED_BUTTON: Color = Color::srgba(0.25, 0.25, 0.25, 0.75);
 const PRESSED_BUTTON: Color = Color::srgba(0.75, 0.75, 0.75, 0.75);
 
+const MAIN_MENU_LAST_BUTTON_TOP: f32 = 92.5;
+const MAIN_MENU_BUTTON_SPACING: f32 = 7.5;
+
 #[derive(Component)]
 pub struct MainMenuComponent;
 
@@ -20,23 +27,43 @@ pub struct MainBackground;
 
 #[derive(Component)]
 pub enum ButtonAction {
+    Continue,
+    LoadGame,
     StartGame,
     Settings,
     Credits,
     Quit
 }
 
+// Everything that has to be reset for a new game, which starts with the opening cutscene.
+#[derive(SystemParam)]
+pub struct NewGame<'w> {
+    slot_progress: SlotProgress<'w>,
+    cutscene: EventWriter<'w, CutsceneEvent>,
+    localization: Localization<'w>,
+}
+
+impl NewGame<'_> {
+    pub fn start(&mut self, slot: u32) {
+        self.slot_progress.reset(slot);
+
+        self.cutscene.write(CutsceneEvent::Started(vec![
+            CutsceneInfo::new(&self.localization.get("cutscene.opening.1"), ""),
+            CutsceneInfo::new(&self.localization.get("cutscene.opening.2"), ""),
+            CutsceneInfo::new(&self.localization.get("cutscene.opening.3"), ""),
+            CutsceneInfo::new(&self.localization.get("cutscene.opening.4"), ""),
+            CutsceneInfo::new("", "cutscenes/opening/1.png"),
+            CutsceneInfo::new("", "cutscenes/opening/2.png"),
+        ], "ost/cutscene.mp3".to_string(), PostCutsceneAction::TransitionTo(Level::StartingRoom)));
+    }
+}
+
+type ButtonVisuals<'a> = (&'a Interaction, &'a mut BackgroundColor, &'a mut BorderColor, Has<Focused>);
+
 pub fn button_visuals_handler(
-    mut interaction_query: Query<
-        (
-            &Interaction,
-            &mut BackgroundColor,
-            &mut BorderColor
-        ),
-        (Changed<Interaction>, With<Button>),
-    >
+    mut interaction_query: Query<ButtonVisuals, (Changed<Interaction>, With<Button>)>
 ) {
-    for (interaction, mut color, mut border_color) in &mut interaction_query {
+    for (interaction, mut color, mut border_color, is_focused) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
@@ -46,6 +73,10 @@ pub fn button_visuals_handler(
                 *color = HOVERED_BUTTON.into();
                 border_color.0 = Color::WHITE;
             }
+            Interaction::None if is_focused => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
             Interaction::None => {
                 *color = NORMAL_BUTTON.into();
                 border_color.0 = Color::BLACK;
@@ -54,24 +85,37 @@ pub fn button_visuals_handler(
     }
 }
 
+type MainMenuButtonInteraction<'a> = (&'a Interaction, &'a ButtonAction);
+
 pub fn main_menu_button_interactions_handler(
-    mut interaction_query: Query<(&Interaction, &ButtonAction), (Changed<Interaction>, With<Button>)>,
+    mut interaction_query: Query<MainMenuButtonInteraction, (Changed<Interaction>, With<Button>)>,
     mut exit: EventWriter<AppExit>,
     mut app_state: ResMut<NextState<AppState>>,
-    mut cutscene: EventWriter<CutsceneEvent>
+    mut load_game: EventWriter<LoadGameEvent>,
+    mut load_menu_mode: ResMut<LoadMenuMode>,
+    mut new_game: NewGame,
 ) {
     for (interaction, action) in &mut interaction_query {
         if let Interaction::Pressed = *interaction  {
             match action {
+                ButtonAction::Continue => {
+                    if let Some(slot) = get_most_recent_save_slot() {
+                        load_game.write(LoadGameEvent(slot));
+                    }
+                },
+                ButtonAction::LoadGame => {
+                    *load_menu_mode = LoadMenuMode::Load;
+                    app_state.set(AppState::LoadMenu);
+                },
                 ButtonAction::StartGame => {
-                    cutscene.write(CutsceneEvent::Started(&[
-                        CutsceneInfo { text: "The legend has it...", background: "" },
-                        CutsceneInfo { text: "Somewhere in these lands, there is a Hidden Gem.", background: ""  },
-                        CutsceneInfo { text: "Capable of giving eternal happiness to those who find it.", background: "" },
-                        CutsceneInfo { text: "A brave Cweampuff decided to look for it...", background: "" },
-                        CutsceneInfo { text: "", background: "cutscenes/opening/1.png" },
-                        CutsceneInfo { text: "", background: "cutscenes/opening/2.png" },
-                    ], "ost/cutscene.mp3", PostCutsceneAction::TransitionTo(Level::StartingRoom)));
+                    match get_new_game_slot() {
+                        Some(slot) => new_game.start(slot),
+                        // Every slot has a save in it, so the player picks which one is lost.
+                        None => {
+                            *load_menu_mode = LoadMenuMode::Overwrite;
+                            app_state.set(AppState::LoadMenu);
+                        }
+                    }
                 },
                 ButtonAction::Settings => {
                     app_state.set(AppState::AudioMenu);
@@ -135,7 +179,7 @@ pub fn spawn_main_menu(
         .with_children(|parent| {
             parent
                 .spawn((
-                    Text::new("CWEAMPUFF'S ADVENTURE"),
+                    LocalizedText("main_menu.title"),
                     TextFont {
                         font: asset_server.load(DEFAULT_FONT),
                         font_size: 90.0,
@@ -149,131 +193,56 @@ pub fn spawn_main_menu(
                 ));
         }).insert(ChildOf(*background));
 
-    commands
-        .spawn((Node {
-            width: Val::Percent(10.0),
-            height: Val::Percent(5.0),
-            top: Val::Percent(70.),
-            left: Val::Percent(45.),
-            position_type: PositionType::Absolute,
-            justify_content: JustifyContent::Center,
-            ..default()
-        }, MainMenuComponent))
-        .with_children(|parent| {
-            parent
-                .spawn((
-                    Button,
-                    ButtonAction::StartGame,
-                    Node {
-                        width: Val::Percent(100.0),
-                        height: Val::Percent(100.0),
-                        border: UiRect::all(Val::Percent(2.0)),
-                        // horizontally center child text
-                        justify_content: JustifyContent::Center,
-                        // vertically center child text
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    BorderColor(Color::BLACK),
-                    BorderRadius::MAX,
-                    BackgroundColor(NORMAL_BUTTON),
-                ))
-                .with_child((
-                    Text::new("Start"),
-                    TextFont {
-                        font: asset_server.load(DEFAULT_FONT),
-                        font_size: 33.0,
-                        ..default()
-                    },
-                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
-                ));
-        }).insert(ChildOf(*background));
+    let mut buttons = vec![];
 
-    commands
-        .spawn((Node {
-            width: Val::Percent(10.0),
-            height: Val::Percent(5.0),
-            top: Val::Percent(77.5),
-            left: Val::Percent(45.),
-            position_type: PositionType::Absolute,
-            justify_content: JustifyContent::Center,
-            ..default()
-        }, MainMenuComponent))
-        .with_children(|parent| {
-            parent
-                .spawn((
-                    Button,
-                    ButtonAction::Settings,
-                    Node {
-                        width: Val::Percent(100.0),
-                        height: Val::Percent(100.0),
-                        border: UiRect::all(Val::Percent(2.0)),
-                        // horizontally center child text
-                        justify_content: JustifyContent::Center,
-                        // vertically center child text
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    BorderColor(Color::BLACK),
-                    BorderRadius::MAX,
-                    BackgroundColor(NORMAL_BUTTON),
-                ))
-                .with_child((
-                    Text::new("Settings"),
-                    TextFont {
-                        font: asset_server.load(DEFAULT_FONT),
-                        font_size: 33.0,
-                        ..default()
-                    },
-                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
-                ));
-        }).insert(ChildOf(*background));
+    if get_most_recent_save_slot().is_some() {
+        buttons.push((ButtonAction::Continue, "main_menu.continue"));
+        buttons.push((ButtonAction::LoadGame, "main_menu.load_game"));
+        buttons.push((ButtonAction::StartGame, "main_menu.new_game"));
+    }
+    else {
+        buttons.push((ButtonAction::StartGame, "main_menu.start"));
+    }
 
-    commands
-        .spawn((Node {
-            width: Val::Percent(10.0),
-            height: Val::Percent(5.0),
-            top: Val::Percent(85.),
-            left: Val::Percent(45.),
-            position_type: PositionType::Absolute,
-            justify_content: JustifyContent::Center,
-            ..default()
-        }, MainMenuComponent))
-        .with_children(|parent| {
-            parent
-                .spawn((
-                    Button,
-                    ButtonAction::Credits,
-                    Node {
-                        width: Val::Percent(100.0),
-                        height: Val::Percent(100.0),
-                        border: UiRect::all(Val::Percent(2.0)),
-                        // horizontally center child text
-                        justify_content: JustifyContent::Center,
-                        // vertically center child text
-                        align_items: AlignItems::Center,
-                        ..default()
-                    },
-                    BorderColor(Color::BLACK),
-                    BorderRadius::MAX,
-                    BackgroundColor(NORMAL_BUTTON),
-                ))
-                .with_child((
-                    Text::new("Credits"),
-                    TextFont {
-                        font: asset_server.load(DEFAULT_FONT),
-                        font_size: 33.0,
-                        ..default()
-                    },
-                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
-                ));
-        }).insert(ChildOf(*background));
+    buttons.push((ButtonAction::Settings, "main_menu.settings"));
+    buttons.push((ButtonAction::Credits, "main_menu.credits"));
+    buttons.push((ButtonAction::Quit, "main_menu.quit"));
+
+    // Buttons are stacked upwards from the bottom of the screen, so the last one always sits at the same spot.
+    let first_button_top = MAIN_MENU_LAST_BUTTON_TOP - (buttons.len() - 1) as f32 * MAIN_MENU_BUTTON_SPACING;
+
+    for (index, (action, label)) in buttons.into_iter().enumerate() {
+        spawn_main_menu_button(&mut commands, &asset_server, *background, action, label, first_button_top + index as f32 * MAIN_MENU_BUTTON_SPACING);
+    }
+
+    if main_menu_bgm_query.is_empty() {
+        let mut playback_settings = PlaybackSettings::default().with_volume(Volume::Linear(0.));
+        playback_settings.mode = PlaybackMode::Loop;
+    
+        commands.spawn((
+            AudioPlayer::new(load_asset(&asset_server, "ost/main.mp3")),
+            LevelBGM,
+            MainMenuAudio,
+            playback_settings
+        ));
+    
+        next_bgm_state.set(LevelBGMState::Changing);
+    }
+}
 
+fn spawn_main_menu_button(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    background: Entity,
+    action: ButtonAction,
+    label: &'static str,
+    top: f32
+) {
     commands
         .spawn((Node {
             width: Val::Percent(10.0),
             height: Val::Percent(5.0),
-            top: Val::Percent(92.5),
+            top: Val::Percent(top),
             left: Val::Percent(45.),
             position_type: PositionType::Absolute,
             justify_content: JustifyContent::Center,
@@ -283,7 +252,7 @@ pub fn spawn_main_menu(
             parent
                 .spawn((
                     Button,
-                    ButtonAction::Quit,
+                    action,
                     Node {
                         width: Val::Percent(100.0),
                         height: Val::Percent(100.0),
@@ -299,7 +268,7 @@ pub fn spawn_main_menu(
                     BackgroundColor(NORMAL_BUTTON),
                 ))
                 .with_child((
-                    Text::new("Quit"),
+                    LocalizedText(label),
                     TextFont {
                         font: asset_server.load(DEFAULT_FONT),
                         font_size: 33.0,
@@ -307,35 +276,25 @@ pub fn spawn_main_menu(
                     },
                     TextColor(Color::srgb(0.9, 0.9, 0.9)),
                 ));
-        }).insert(ChildOf(*background));
-
-    if main_menu_bgm_query.is_empty() {
-        let mut playback_settings = PlaybackSettings::default().with_volume(Volume::Linear(0.));
-        playback_settings.mode = PlaybackMode::Loop;
-    
-        commands.spawn((
-            AudioPlayer::new(load_asset(&asset_server, "ost/main.mp3")),
-            LevelBGM,
-            MainMenuAudio,
-            playback_settings
-        ));
-    
-        next_bgm_state.set(LevelBGMState::Changing);
-    }
+        }).insert(ChildOf(background));
 }
 
+type MainMenuNodeFilter = (With<Node>, With<MainMenuComponent>, Without<Camera2d>, Without<FadeInFadeOutNode>);
+
 pub fn despawn_main_menu(
     mut commands: Commands, 
-    query: Query<Entity, (With<Node>, With<MainMenuComponent>, Without<Camera2d>, Without<FadeInFadeOutNode>)>
+    query: Query<Entity, MainMenuNodeFilter>
 ) {
     for entity in query.iter() {
         commands.entity(entity).despawn();
     }
 }
 
+type MainBackgroundNodeFilter = (With<Node>, With<MainBackground>, Without<Camera2d>, Without<FadeInFadeOutNode>);
+
 pub fn despawn_background(
     mut commands: Commands, 
-    query: Query<Entity, (With<Node>, With<MainBackground>, Without<Camera2d>, Without<FadeInFadeOutNode>)>
+    query: Query<Entity, MainBackgroundNodeFilter>
 ) {
     for entity in query.iter() {
         commands.entity(entity).despawn();
@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+
+use crate::{controls::{ActionInput, GameAction, STICK_DEADZONE}, controls_menu::PendingRebind, main_menu::{HOVERED_BUTTON, NORMAL_BUTTON}};
+
+// Marks the menu button that will be activated by the keyboard or controller.
+#[derive(Component)]
+pub struct Focused;
+
+#[derive(Default)]
+pub struct MenuNavigationState {
+    // Button that was pressed through the keyboard or controller last frame and needs to be released.
+    activated_button: Option<Entity>,
+    stick_was_tilted: bool
+}
+
+pub fn menu_focus_navigation(
+    action_input: ActionInput,
+    gamepads: Query<&Gamepad>,
+    pending_rebind: Res<PendingRebind>,
+    mut buttons: Query<(Entity, &GlobalTransform, &mut Interaction, Has<Focused>), With<Button>>,
+    mut commands: Commands,
+    mut state: Local<MenuNavigationState>,
+) {
+    if let Some(activated_button) = state.activated_button.take() {
+        if let Ok((_, _, mut interaction, _)) = buttons.get_mut(activated_button) {
+            if *interaction == Interaction::Pressed {
+                *interaction = Interaction::None;
+            }
+        }
+    }
+
+    // While a key is being rebound, every key press belongs to the controls menu.
+    if pending_rebind.is_waiting() {
+        return;
+    }
+
+    let mut direction = Vec2::ZERO;
+    let mut stick_is_tilted = false;
+
+    if action_input.just_pressed(GameAction::LookUp) {
+        direction = Vec2::Y;
+    }
+    else if action_input.just_pressed(GameAction::LookDown) {
+        direction = Vec2::NEG_Y;
+    }
+    else if action_input.just_pressed(GameAction::MoveLeft) {
+        direction = Vec2::NEG_X;
+    }
+    else if action_input.just_pressed(GameAction::MoveRight) {
+        direction = Vec2::X;
+    }
+
+    // The left stick navigates in any direction, not only left and right like in gameplay.
+    for gamepad in gamepads.iter() {
+        let stick = gamepad.left_stick();
+
+        if stick.length() > STICK_DEADZONE {
+            stick_is_tilted = true;
+
+            // Only move once per tilt, otherwise focus would fly through the whole menu.
+            if !state.stick_was_tilted {
+                direction = stick;
+            }
+        }
+    }
+
+    let activate = action_input.just_pressed(GameAction::Confirm);
+
+    state.stick_was_tilted = stick_is_tilted;
+
+    if direction == Vec2::ZERO && !activate {
+        return;
+    }
+
+    let focused_button = buttons.iter().find(|(_, _, _, is_focused)| *is_focused).map(|(entity, transform, _, _)| (entity, transform.translation().truncate()));
+
+    let Some((focused_entity, focused_position)) = focused_button else {
+        // The first input only reveals the focus, starting from the top left button.
+        let first_button = buttons.iter()
+            .map(|(entity, transform, _, _)| (entity, transform.translation().truncate()))
+            .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)));
+
+        if let Some((entity, _)) = first_button {
+            commands.entity(entity).insert(Focused);
+        }
+
+        return;
+    };
+
+    if activate {
+        if let Ok((_, _, mut interaction, _)) = buttons.get_mut(focused_entity) {
+            *interaction = Interaction::Pressed;
+            state.activated_button = Some(focused_entity);
+        }
+
+        return;
+    }
+
+    // UI coordinates grow downwards, while stick and d-pad up is positive.
+    let direction = Vec2::new(direction.x, -direction.y).normalize();
+
+    let next_button = buttons.iter()
+        .filter(|(entity, _, _, _)| *entity != focused_entity)
+        .filter_map(|(entity, transform, _, _)| {
+            let offset = transform.translation().truncate() - focused_position;
+            let distance_along = offset.dot(direction);
+
+            if distance_along <= 0. {
+                return None;
+            }
+
+            // Prefer buttons that are straight in the pressed direction over closer ones off to the side.
+            let distance_across = offset.perp_dot(direction).abs();
+
+            Some((entity, distance_along + distance_across * 2.))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    if let Some((next_entity, _)) = next_button {
+        commands.entity(focused_entity).remove::<Focused>();
+        commands.entity(next_entity).insert(Focused);
+    }
+}
+
+type ButtonColors<'a> = (&'a mut BackgroundColor, &'a mut BorderColor, &'a Interaction);
+
+type NewlyFocusedButton = (Added<Focused>, With<Focused>, With<Button>);
+
+pub fn focus_visuals_handler(
+    mut focused_buttons: Query<ButtonColors, NewlyFocusedButton>,
+    mut buttons: Query<ButtonColors, (Without<Focused>, With<Button>)>,
+    mut removed_focus: RemovedComponents<Focused>,
+) {
+    for (mut color, mut border_color, interaction) in focused_buttons.iter_mut() {
+        if *interaction == Interaction::None {
+            *color = HOVERED_BUTTON.into();
+            border_color.0 = Color::WHITE;
+        }
+    }
+
+    for entity in removed_focus.read() {
+        if let Ok((mut color, mut border_color, interaction)) = buttons.get_mut(entity) {
+            if *interaction == Interaction::None {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
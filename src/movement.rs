@@ -3,6 +3,7 @@ use bevy_rapier2d::{prelude::{Collider, CollisionEvent, Velocity}, rapier::prelu
 
 use crate::{audio_settings::AudioSettings, camera::CameraUpDownMovable, level::level_layout::CollisionType, Cweampuff, FloorCollider, CWEAMPUFF_DIAMETER};
 use crate::asset_loader::load_asset;
+use crate::controls::{ActionInput, GameAction};
 
 const CWEAMPUFF_SPEED: f32 = 500.0;
 const MAX_CWEAMPUFF_VERTICAL_VELOCITY: f32 = 800.0;
@@ -40,7 +41,7 @@ pub struct Movable {
 }
 
 pub fn cweampuff_move(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    action_input: ActionInput,
     mut cweampuff_transform_velocity: Single<(&mut Velocity, &mut Movable), With<Cweampuff>>
 ) {
     let (cweampuff_velocity, cweampuff_movable) = &mut *cweampuff_transform_velocity;
@@ -49,11 +50,11 @@ pub fn cweampuff_move(
         return;
     }
 
-    if keyboard_input.any_just_released([KeyCode::ArrowLeft, KeyCode::KeyA, KeyCode::KeyD, KeyCode::ArrowRight]) {
+    if action_input.any_just_released([GameAction::MoveLeft, GameAction::MoveRight]) {
         cweampuff_velocity.linvel.x = 0.;
     } 
 
-    if keyboard_input.any_pressed([KeyCode::ArrowLeft, KeyCode::KeyA]) {
+    if action_input.pressed(GameAction::MoveLeft) {
         if cweampuff_movable.hugging_right_wall {
             cweampuff_velocity.linvel.x = 0.;
             return;
@@ -68,7 +69,7 @@ pub fn cweampuff_move(
         }
     }
 
-    if keyboard_input.any_pressed([KeyCode::ArrowRight, KeyCode::KeyD]) {
+    if action_input.pressed(GameAction::MoveRight) {
         if cweampuff_movable.hugging_left_wall {
             cweampuff_velocity.linvel.x = 0.;
             return;
@@ -85,7 +86,7 @@ pub fn cweampuff_move(
 }
 
 pub fn cweampuff_jump(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    action_input: ActionInput,
     mut cweampuff_jumper: Single<(&mut Jumper, &mut Velocity, &mut Movable, &Cweampuff), With<Cweampuff>>,
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -93,7 +94,7 @@ pub fn cweampuff_jump(
 ) {
     let (jumper, velocity, movable, cweampuff) = &mut *cweampuff_jumper;
 
-    let jump_released = keyboard_input.just_released(KeyCode::Space);
+    let jump_released = action_input.just_released(GameAction::Jump);
 
     if jump_released {
         jumper.is_jump_available = true;
@@ -113,7 +114,7 @@ pub fn cweampuff_jump(
         }
     }
 
-    if keyboard_input.just_pressed(KeyCode::Space) && jumper.is_jump_available && (!jumper.is_jumping || (jumper.is_next_jump_doublejump && cweampuff.has_double_jump)) {
+    if action_input.just_pressed(GameAction::Jump) && jumper.is_jump_available && (!jumper.is_jumping || (jumper.is_next_jump_doublejump && cweampuff.has_double_jump)) {
         velocity.linvel.y = jumper.jump_impulse;
         jumper.is_jumping = true;
         jumper.is_jump_available = false;
@@ -174,7 +175,7 @@ pub fn coyote_jump_buffer_monitor(
 }
 
 pub fn cweampuff_dash(
-    keyboard_input: Res<ButtonInput<KeyCode>>, 
+    action_input: ActionInput,
     mut cweampuff_dasher: Single<(&mut Dasher, &mut Velocity, &mut Movable, &Cweampuff), With<Cweampuff>>,
     mut commands: Commands,
     asset_server: Res<AssetServer>,
@@ -186,7 +187,7 @@ pub fn cweampuff_dash(
         return;
     }
 
-    if !keyboard_input.just_pressed(KeyCode::KeyX) || dasher.dash_cooldown - dasher.time_passed_since_dash > 0.01 ||
+    if !action_input.just_pressed(GameAction::Dash) || dasher.dash_cooldown - dasher.time_passed_since_dash > 0.01 ||
         (!movable.touching_ground && !dasher.is_dash_available)  {
         return;
     }
@@ -1,19 +1,29 @@
+pub mod conversation_action;
 pub mod conversation_state;
 pub mod conversation_entry;
+pub mod dialogue_asset;
+pub mod dialogue_markup;
 pub mod dialog_state;
+pub mod voice;
 
-use bevy::{audio::Volume, prelude::*, ui::widget::NodeImageMode};
+use bevy::{audio::Volume, ecs::system::SystemParam, prelude::*, ui::widget::NodeImageMode};
 use bevy_rapier2d::prelude::CollisionEvent;
-use conversation_entry::{ConversationEntry, ConversationPosition};
+use std::collections::BTreeSet;
+
+use conversation_action::{ConversationAction, ConversationActionEvent};
+use conversation_entry::{get_label_index, get_next_entry_index, ConversationEntry, ConversationPosition};
 use conversation_state::ConversationState;
+use dialogue_markup::{strip_markup, DialogTypewriter, GlyphBasePositions, DIALOG_FONT_SIZE, DIALOG_TEXT_COLOR};
 use dialog_state::DialogState;
+use voice::{Voice, VOICE_BLIP_CHARS};
 
-use crate::{audio_settings::AudioSettings, cutscene::CutsceneEvent, fade_in_fade_out::FADE_DELTA, interactable::{interaction_state::InteractionState, Interactable}, level::level_layout::{BreakableWall, EntityInfo}, main_menu::DEFAULT_FONT, Cweampuff};
+use crate::{audio_settings::AudioSettings, backlog_menu::{BacklogEntry, DialogueBacklog}, fade_in_fade_out::FADE_DELTA, interactable::{interaction_state::InteractionState, Interactable}, level::level_layout::EntityInfo, main_menu::{DEFAULT_FONT, NORMAL_BUTTON}, menu_navigation::Focused, Cweampuff};
 use crate::asset_loader::load_asset;
+use crate::dialogue_settings::DialogueSettings;
+use crate::localization::Localization;
+use crate::controls::{ActionInput, GameAction};
 use crate::movement::check_entities;
 
-pub const CWEAMPUFF: &str = "cweampuff";
-pub const CWEAMPUFFS: &str = "cweampuffs";
 pub const COOL_CWEAMPUFF: &str = "cool cweampuff";
 pub const OG_CWEAMPUFF: &str = "og cweampuff";
 pub const MASKED_CWEAMPUFF: &str = "masked cweampuff";
@@ -32,6 +42,21 @@ pub const OG_DRONE: &str = "og drone";
 pub const GRIM: &str = "grim";
 pub const CLIPPER_DRONE: &str = "clipper drone";
 
+// Every NPC that has a dialogue file.
+pub const NPC_NAMES: [&str; 17] = [
+    COOL_CWEAMPUFF, OG_CWEAMPUFF, MASKED_CWEAMPUFF, RICH_CWEAMPUFF, MILK_ASLEEP, MILK, MINAWAN, OG_MINAWAN, SCIENTIST_MINAWAN,
+    CREW_MEMBER, OG_CREW_MEMBER, OBSERVER_CREW_MEMBER, GLORP, DRONE, OG_DRONE, GRIM, CLIPPER_DRONE
+];
+
+// How long each read line stays on screen while skipping through them.
+const SKIP_LINE_SECS: f32 = 0.1;
+const NAME_PLATE_FONT_SIZE: f32 = 40.;
+
+// Speaker names are shown through the string table, e.g. "og cweampuff" is shown as "npc.og_cweampuff".
+pub fn get_name_localization_id(npc_name: &str) -> String {
+    format!("npc.{}", npc_name.replace(' ', "_"))
+}
+
 #[derive(Component)]
 pub struct DialogNode;
 
@@ -41,21 +66,181 @@ pub struct DialogTextNode;
 #[derive(Component)]
 pub struct DialogText;
 
+// Sits above the dialog box and shows who is speaking, it's hidden when nobody is.
+#[derive(Component)]
+pub struct DialogNamePlate;
+
+#[derive(Component)]
+pub struct DialogNameText;
+
+// The portrait of whoever last spoke from this position.
 #[derive(Component)]
-pub struct LeftCharacterImageNode;
+pub struct CharacterImageNode(pub ConversationPosition);
 
 #[derive(Component)]
-pub struct RightCharacterImageNode;
+pub struct ConversationChoiceNode;
+
+// Index of the choice within the current conversation entry.
+#[derive(Component)]
+pub struct ConversationChoiceButton(usize);
+
+// Flags set by conversation choices, they are saved alongside the Cweampuff.
+#[derive(Resource, Default)]
+pub struct DialogueFlags(pub BTreeSet<String>);
+
+// Ids of the conversation entries the player has moved past, they are saved alongside the dialogue flags.
+// Entries without an id are never counted as read.
+#[derive(Resource, Default)]
+pub struct ReadDialogue(pub BTreeSet<String>);
+
+// Choices made during the current conversation.
+#[derive(Resource, Default)]
+pub struct ConversationBranch {
+    // Entry the conversation continues from once a choice has been picked.
+    next_index: Option<usize>,
+    made_choice: bool,
+    triggers_after_conversation: bool,
+    actions: Vec<ConversationAction>
+}
+
+impl ConversationBranch {
+    fn runs_after_conversation(&self) -> bool {
+        !self.made_choice || self.triggers_after_conversation
+    }
+}
+
+// Holding skip shows lines that were read before in full and moves past them, stopping at the first one that wasn't.
+#[derive(Resource, Default)]
+pub struct ConversationSkip {
+    is_skipping: bool,
+    cooldown: f32
+}
+
+// Whatever moves the conversation on to its next line.
+#[derive(SystemParam)]
+pub struct ConversationInput<'w> {
+    action_input: ActionInput<'w>,
+    mouse_input: Res<'w, ButtonInput<MouseButton>>,
+    conversation_skip: Res<'w, ConversationSkip>,
+}
+
+impl ConversationInput<'_> {
+    fn is_skipping(&self) -> bool {
+        self.conversation_skip.is_skipping
+    }
+
+    fn continues_conversation(&self) -> bool {
+        self.is_skipping() ||
+        self.action_input.any_just_pressed([GameAction::Interact, GameAction::Jump, GameAction::Confirm]) ||
+        self.mouse_input.any_just_pressed([MouseButton::Left, MouseButton::Right])
+    }
+}
+
+// The dialog box, along with the portraits of whoever is talking.
+#[derive(SystemParam)]
+pub struct DialogBox<'w, 's> {
+    typewriter_query: Query<'w, 's, &'static mut DialogTypewriter, With<DialogText>>,
+    text_node_query: Query<'w, 's, &'static mut Node, With<DialogTextNode>>,
+    character_image_query: Query<'w, 's, (&'static mut ImageNode, &'static CharacterImageNode)>,
+    next_dialog_state: ResMut<'w, NextState<DialogState>>,
+    asset_server: Res<'w, AssetServer>,
+}
+
+impl DialogBox<'_, '_> {
+    // Returns whether the line was still being typed out.
+    fn complete_line(&mut self) -> bool {
+        let mut was_typing = false;
+
+        for mut typewriter in self.typewriter_query.iter_mut() {
+            if typewriter.is_started() && !typewriter.is_finished() {
+                typewriter.complete();
+                was_typing = true;
+            }
+        }
+
+        was_typing
+    }
+
+    fn show_entry(&mut self, entry: &ConversationEntry, is_new_line: bool) {
+        if is_new_line {
+            for mut typewriter in self.typewriter_query.iter_mut() {
+                typewriter.reset();
+            }
+        }
+
+        let position = entry.position;
+
+        for (mut character_image, CharacterImageNode(image_position)) in self.character_image_query.iter_mut() {
+            if *image_position != position {
+                continue;
+            }
+
+            let new_image_handle = load_asset(&self.asset_server, format!("npcs/{}/{}.png", entry.npc_name, entry.emotion));
+
+            if new_image_handle != character_image.image {
+                character_image.color.set_alpha(0.);
+                character_image.image = new_image_handle;
+            }
+        }
+
+        self.next_dialog_state.set(DialogState::CharacterTalking(position));
+
+        for mut node in self.text_node_query.iter_mut() {
+            node.left = Val::Percent(position.get_text_box_left());
+        }
+    }
+
+    fn close(&mut self) {
+        self.next_dialog_state.set(DialogState::None);
+    }
+}
+
+// Sits above the dialog box and shows who is speaking.
+#[derive(SystemParam)]
+pub struct DialogNamePlateText<'w, 's> {
+    name_plate_query: Query<'w, 's, &'static mut Visibility, With<DialogNamePlate>>,
+    name_text_query: Query<'w, 's, &'static mut Text, (With<DialogNameText>, Without<DialogText>)>,
+}
+
+impl DialogNamePlateText<'_, '_> {
+    // Narration has no speaker, so the name plate is hidden for it.
+    fn show(&mut self, npc_name: &str, localization: &Localization) {
+        let has_speaker = !npc_name.is_empty();
+
+        for mut visibility in self.name_plate_query.iter_mut() {
+            *visibility = if has_speaker { Visibility::Inherited } else { Visibility::Hidden };
+        }
+
+        if has_speaker {
+            for mut name_text in self.name_text_query.iter_mut() {
+                name_text.0 = localization.get(&get_name_localization_id(npc_name));
+            }
+        }
+    }
+}
 
 
 #[derive(Component, Clone)]
 pub struct NPC {
-    pub name: &'static str,
+    pub name: String,
     pub floor_info: EntityInfo,
     pub is_active: bool,
-    pub conversation: &'static [ConversationEntry],
+    pub conversation: Vec<ConversationEntry>,
     pub current_conversation_index: usize,
-    pub after_conversation_func: fn(&mut Cweampuff, &mut Commands, &Query<(Entity, &BreakableWall), (With<BreakableWall>, Without<Camera2d>)>, &mut EventWriter<CutsceneEvent>)
+    pub after_conversation: Vec<ConversationAction>
+}
+
+impl NPC {
+    pub fn new(name: String, floor_info: EntityInfo) -> Self {
+        Self {
+            name,
+            floor_info,
+            is_active: false,
+            conversation: Vec::new(),
+            current_conversation_index: 0,
+            after_conversation: Vec::new()
+        }
+    }
 }
 
 pub fn npc_collision_reader(
@@ -90,12 +275,12 @@ pub fn npc_collision_reader(
 }
 
 pub fn npc_start_interaction_input_reader(
-    keyboard_input: Res<ButtonInput<KeyCode>>, 
+    action_input: ActionInput,
     npcs: Query<&NPC, (With<NPC>, With<Interactable>)>,
     mut dialog_state: ResMut<NextState<ConversationState>>,
     mut npc_interaction_state: ResMut<NextState<InteractionState>> 
 ) {
-    if !keyboard_input.just_pressed(KeyCode::KeyE) {
+    if !action_input.just_pressed(GameAction::Interact) {
         return;
     }
 
@@ -142,53 +327,69 @@ pub fn spawn_conversation_resources(
                     BorderColor(Color::Srgba(Srgba { red: 0.75, green: 0.75, blue: 0.75, alpha: 1.0 })),
                 ))
                 .with_children(|parent| {
+                    parent
+                        .spawn((
+                            Node {
+                                top: Val::Px(-95.),
+                                left: Val::Px(0.),
+                                position_type: PositionType::Absolute,
+                                border: UiRect::all(Val::Px(5.)),
+                                padding: UiRect::axes(Val::Px(20.), Val::Px(5.)),
+                                ..default()
+                            },
+                            Visibility::Hidden,
+                            DialogNamePlate,
+                            BorderRadius::all(Val::Px(15.)),
+                            BorderColor(Color::Srgba(Srgba { red: 0.75, green: 0.75, blue: 0.75, alpha: 1.0 })),
+                            BackgroundColor(Color::Srgba(Srgba { red: 0.1, green: 0.1, blue: 0.1, alpha: 1.0 })),
+                        ))
+                        .with_child((
+                            Text::new(""),
+                            TextFont {
+                                font: asset_server.load(DEFAULT_FONT),
+                                font_size: NAME_PLATE_FONT_SIZE,
+                                ..default()
+                            },
+                            DialogNameText,
+                            TextColor(Color::srgb(0.95, 0.8, 0.45)),
+                        ));
+
                     parent.spawn((
                         Text::new(""),
                         TextFont {
                             font: asset_server.load(DEFAULT_FONT),
-                            font_size: 50.0,
+                            font_size: DIALOG_FONT_SIZE,
                             ..default()
                         },
                         DialogText,
-                        TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                        DialogTypewriter::default(),
+                        GlyphBasePositions::default(),
+                        TextColor(DIALOG_TEXT_COLOR),
                         TextLayout::new(JustifyText::Left, LineBreak::WordBoundary)
                     ));
                 });
 
-            // LEFT CHARACTER IMAGE
-            parent.spawn((
-                    ImageNode::default()
-                        .with_flip_x()
-                        .with_color(Color::Srgba(Srgba::new(1.0, 1.0, 1.0, 0.0)))
-                        .with_mode(NodeImageMode::Auto),
-                    Node {
-                        width: Val::Percent(35.0),
-                        height: Val::Percent(65.0),
-                        top: Val::Percent(35.0),
-                        left: Val::Percent(0.),
-                        justify_content: JustifyContent::Start,
-                        position_type: PositionType::Absolute,
-                        ..default()
-                    },
-                    LeftCharacterImageNode,
-                ));
-            
-            // RIGHT CHARACTER IMAGE
-            parent.spawn((
-                    ImageNode::default()
-                        .with_color(Color::Srgba(Srgba::new(1.0, 1.0, 1.0, 0.0)))
-                        .with_mode(NodeImageMode::Auto),
-                    Node {
-                        width: Val::Percent(35.0),
-                        height: Val::Percent(65.0),
-                        top: Val::Percent(35.0),
-                        left: Val::Percent(65.),
-                        position_type: PositionType::Absolute,
-                        justify_content: JustifyContent::Start,
-                        ..default()
-                    },
-                    RightCharacterImageNode,
-                ));
+            // CHARACTER IMAGES
+            for position in ConversationPosition::PORTRAIT_SLOTS {
+                parent.spawn((
+                        ImageNode {
+                            flip_x: position.is_flipped(),
+                            ..default()
+                        }
+                            .with_color(Color::Srgba(Srgba::new(1.0, 1.0, 1.0, 0.0)))
+                            .with_mode(NodeImageMode::Auto),
+                        Node {
+                            width: Val::Percent(35.0),
+                            height: Val::Percent(65.0),
+                            top: Val::Percent(35.0),
+                            left: Val::Percent(position.get_portrait_left()),
+                            justify_content: JustifyContent::Start,
+                            position_type: PositionType::Absolute,
+                            ..default()
+                        },
+                        CharacterImageNode(position),
+                    ));
+            }
         });
 
     for mut audio in audio_query.iter_mut() {
@@ -212,147 +413,316 @@ pub fn despawn_conversation_resources(
     }
 }
 
+pub fn conversation_skip_input_reader(
+    action_input: ActionInput,
+    npcs_query: Query<&NPC, With<NPC>>,
+    read_dialogue: Res<ReadDialogue>,
+    mut typewriter_query: Query<&mut DialogTypewriter, With<DialogText>>,
+    mut conversation_skip: ResMut<ConversationSkip>,
+    time: Res<Time>,
+) {
+    conversation_skip.is_skipping = false;
+    conversation_skip.cooldown = (conversation_skip.cooldown - time.delta_secs()).max(0.);
+
+    let Some(npc) = npcs_query.iter().find(|f| f.is_active) else {
+        return;
+    };
+
+    let current_entry = npc.conversation.get(npc.current_conversation_index);
+    let is_choosing = current_entry.is_some_and(|entry| !entry.choices.is_empty());
+    let is_read = current_entry.and_then(|entry| entry.id.as_ref()).is_some_and(|id| read_dialogue.0.contains(id));
+
+    if !action_input.pressed(GameAction::Skip) || !is_read || is_choosing {
+        return;
+    }
+
+    for mut typewriter in typewriter_query.iter_mut() {
+        if typewriter.is_started() {
+            typewriter.complete();
+            conversation_skip.is_skipping = conversation_skip.cooldown == 0.;
+        }
+    }
+
+    if conversation_skip.is_skipping {
+        conversation_skip.cooldown = SKIP_LINE_SECS;
+    }
+}
+
 pub fn conversation_input_reader(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mouse_input: Res<ButtonInput<MouseButton>>,
-    mut cweampuff: Single<&mut Cweampuff, With<Cweampuff>>,
+    conversation_input: ConversationInput,
+    mut dialog_box: DialogBox,
     mut conversation_state: ResMut<NextState<ConversationState>>,
-    mut text_query: Query<&mut Text, With<DialogText>>,
-    mut text_node_query: Query<&mut Node, With<DialogTextNode>>,
-    mut left_npc_image: Single<&mut ImageNode, (With<LeftCharacterImageNode>, Without<RightCharacterImageNode>)>,
-    mut right_npc_image: Single<&mut ImageNode, (With<RightCharacterImageNode>, Without<LeftCharacterImageNode>)>,
     mut npcs_query: Query<&mut NPC, With<NPC>>,
-    mut next_dialog_state: ResMut<NextState<DialogState>>,
-    mut commands: Commands,
-    mut cutscene_writer: EventWriter<CutsceneEvent>,
-    breakable_walls: Query<(Entity, &BreakableWall), (With<BreakableWall>, Without<Camera2d>)>,
-    asset_server: Res<AssetServer>
+    mut action_writer: EventWriter<ConversationActionEvent>,
+    mut conversation_branch: ResMut<ConversationBranch>,
+    mut read_dialogue: ResMut<ReadDialogue>,
 ) {
     if let Some(mut npc) = npcs_query.iter_mut().find(|f| f.is_active) {
-        let continue_conversation = keyboard_input.any_just_pressed([KeyCode::KeyE, KeyCode::Space, KeyCode::Enter]) ||
-                                          mouse_input.any_just_pressed([MouseButton::Left, MouseButton::Right]);
+        let is_choosing = npc.conversation.get(npc.current_conversation_index).is_some_and(|entry| !entry.choices.is_empty());
+        let is_skipping = conversation_input.is_skipping();
+
+        // While choices are shown, only picking one of them moves the conversation along.
+        let continue_conversation = if is_choosing {
+            conversation_branch.next_index.is_some()
+        }
+        else {
+            conversation_input.continues_conversation()
+        };
+
+        // The first press finishes typing out the current line, only the next one moves on.
+        if continue_conversation && !is_choosing && !is_skipping && dialog_box.complete_line() {
+            return;
+        }
 
         if !continue_conversation && npc.current_conversation_index != 0 {
             return;
         }
 
         if continue_conversation {
-            npc.current_conversation_index += 1;
+            if let Some(id) = npc.conversation.get(npc.current_conversation_index).and_then(|entry| entry.id.clone()) {
+                read_dialogue.0.insert(id);
+            }
+
+            npc.current_conversation_index = match conversation_branch.next_index.take() {
+                Some(next_index) => next_index,
+                None => get_next_entry_index(&npc.conversation, npc.current_conversation_index)
+            };
         }
 
-        {
-            let current_conversation_info = match npc.conversation.get(npc.current_conversation_index) {
-                Some(info) => info,
-                None => {
-                    conversation_state.set(ConversationState::Finished);
-                    npc.current_conversation_index = 0;
-                    next_dialog_state.set(DialogState::None);
-                    (npc.after_conversation_func)(&mut cweampuff, &mut commands, &breakable_walls, &mut cutscene_writer);
+        let Some(current_conversation_info) = npc.conversation.get(npc.current_conversation_index) else {
+            conversation_state.set(ConversationState::Finished);
+            npc.current_conversation_index = 0;
+            dialog_box.close();
 
-                    return;
-                }
-            };
-        
-            for mut text in text_query.iter_mut() {
-                if npc.current_conversation_index != 0 {
-                    **text = String::new();
-                }
+            let mut actions = if conversation_branch.runs_after_conversation() {
+                npc.after_conversation.clone()
             }
+            else {
+                Vec::new()
+            };
 
-            let npc_image_path = format!("npcs/{}/{}.png", current_conversation_info.npc_name, current_conversation_info.emotion);
-
-            match current_conversation_info.position {
-                ConversationPosition::Left => {
-                    let new_image_handle = load_asset(&asset_server, npc_image_path);
-                    
-                    if new_image_handle != left_npc_image.image {
-                        left_npc_image.color.set_alpha(0.);
-                        left_npc_image.image = new_image_handle;
-                    }
-
-                    next_dialog_state.set(DialogState::LeftCharacterTalking);
-
-                    for mut node in text_node_query.iter_mut() {
-                        node.left = Val::Percent(35.);
-                    }
-                },
-                ConversationPosition::Right => {
-                    let new_image_handle = load_asset(&asset_server, npc_image_path);
-                    
-                    if new_image_handle != right_npc_image.image {
-                        right_npc_image.color.set_alpha(0.);
-                        right_npc_image.image = new_image_handle;
-                    }
-                    
-                    next_dialog_state.set(DialogState::RightCharacterTalking);
-
-                    for mut node in text_node_query.iter_mut() {
-                        node.left = Val::Percent(25.);
-                    }
-                }
-            }
+            actions.append(&mut conversation_branch.actions);
+            action_writer.write(ConversationActionEvent(actions));
+
+            *conversation_branch = ConversationBranch::default();
+
+            return;
+        };
+
+        dialog_box.show_entry(current_conversation_info, continue_conversation);
+    }
+}
+
+// The choice buttons shown under the dialog box while the conversation waits for a choice.
+#[derive(SystemParam)]
+pub struct ConversationChoices<'w, 's> {
+    dialog_node: Single<'w, Entity, (With<DialogNode>, Without<Camera2d>)>,
+    choice_nodes: Query<'w, 's, Entity, (With<ConversationChoiceNode>, Without<Camera2d>)>,
+    choice_buttons: Query<'w, 's, (&'static Interaction, &'static ConversationChoiceButton), With<Button>>,
+}
+
+impl ConversationChoices<'_, '_> {
+    fn get_pressed_choice(&self) -> Option<usize> {
+        self.choice_buttons.iter()
+            .find(|(interaction, _)| **interaction == Interaction::Pressed)
+            .map(|(_, ConversationChoiceButton(choice_index))| *choice_index)
+    }
+
+    fn close(&self, commands: &mut Commands) {
+        for entity in self.choice_nodes.iter() {
+            commands.entity(entity).despawn();
         }
-    }    
+    }
 }
 
-pub fn left_character_talking(
-    mut left_npc_image: Single<&mut ImageNode, (With<LeftCharacterImageNode>, Without<RightCharacterImageNode>)>,
-    mut right_npc_image: Single<&mut ImageNode, (With<RightCharacterImageNode>, Without<LeftCharacterImageNode>)>,
-    time: Res<Time>,
+pub fn conversation_choice_handler(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    npcs_query: Query<&NPC, With<NPC>>,
+    conversation_choices: ConversationChoices,
+    mut conversation_branch: ResMut<ConversationBranch>,
+    localization: Localization,
 ) {
-    let left_npc_alpha = left_npc_image.color.alpha();
+    let Some(npc) = npcs_query.iter().find(|f| f.is_active) else {
+        return;
+    };
 
-    if left_npc_alpha < 1.0 {
-        left_npc_image.color.set_alpha(left_npc_alpha + time.delta_secs() * FADE_DELTA);
+    let Some(entry) = npc.conversation.get(npc.current_conversation_index).filter(|entry| !entry.choices.is_empty()) else {
+        return;
+    };
+
+    if conversation_choices.choice_nodes.is_empty() {
+        spawn_conversation_choices(&mut commands, &asset_server, *conversation_choices.dialog_node, entry.choices.iter().map(|choice| strip_markup(&localization.get_or(choice.id.as_deref(), &choice.text))));
+        return;
     }
 
-    let right_npc_alpha = right_npc_image.color.alpha();
+    let Some(choice_index) = conversation_choices.get_pressed_choice() else {
+        return;
+    };
 
-    if right_npc_alpha > 0.0 {
-        right_npc_image.color.set_alpha(right_npc_alpha - time.delta_secs() * FADE_DELTA * 2.);
-    }
+    let choice = &entry.choices[choice_index];
+
+    conversation_branch.next_index = Some(match &choice.jump_to {
+        Some(label) => get_label_index(&npc.conversation, label),
+        None => npc.current_conversation_index + 1
+    });
+    conversation_branch.made_choice = true;
+    conversation_branch.triggers_after_conversation |= choice.triggers_after_conversation;
+    conversation_branch.actions.extend(choice.after_conversation.iter().cloned());
+
+    conversation_choices.close(&mut commands);
 }
 
-pub fn right_character_talking(
-    mut left_npc_image: Single<&mut ImageNode, (With<LeftCharacterImageNode>, Without<RightCharacterImageNode>)>,
-    mut right_npc_image: Single<&mut ImageNode, (With<RightCharacterImageNode>, Without<LeftCharacterImageNode>)>,
+fn spawn_conversation_choices(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    dialog_node: Entity,
+    choices: impl Iterator<Item = String>
+) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(40.0),
+                top: Val::Percent(15.0),
+                left: Val::Percent(30.),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Percent(2.),
+                ..default()
+            },
+            ConversationChoiceNode,
+            ChildOf(dialog_node)
+        ))
+        .with_children(|parent| {
+            for (index, choice) in choices.enumerate() {
+                let mut button = parent.spawn((
+                    Button,
+                    ConversationChoiceButton(index),
+                    Node {
+                        width: Val::Percent(100.0),
+                        min_height: Val::Px(70.0),
+                        border: UiRect::all(Val::Px(5.0)),
+                        padding: UiRect::horizontal(Val::Px(20.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor(Color::BLACK),
+                    BorderRadius::all(Val::Px(20.)),
+                    BackgroundColor(NORMAL_BUTTON),
+                ));
+
+                button.with_child((
+                    Text::new(choice),
+                    TextFont {
+                        font: asset_server.load(DEFAULT_FONT),
+                        font_size: 40.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                ));
+
+                // The first choice starts focused, so the keyboard and controller can pick one right away.
+                if index == 0 {
+                    button.insert(Focused);
+                }
+            }
+        });
+}
+
+// The speaker's portrait fades in, while everyone else's fades out.
+pub fn character_talking(
+    mut character_image_query: Query<(&mut ImageNode, &CharacterImageNode)>,
+    dialog_state: Res<State<DialogState>>,
     time: Res<Time>,
 ) {
-    let right_npc_alpha = right_npc_image.color.alpha();
+    let DialogState::CharacterTalking(position) = dialog_state.get() else {
+        return;
+    };
+
+    for (mut character_image, CharacterImageNode(image_position)) in character_image_query.iter_mut() {
+        let alpha = character_image.color.alpha();
 
-    if right_npc_alpha < 1.0 {
-        right_npc_image.color.set_alpha(right_npc_alpha + time.delta_secs() * FADE_DELTA);
+        if image_position == position {
+            if alpha < 1.0 {
+                character_image.color.set_alpha(alpha + time.delta_secs() * FADE_DELTA);
+            }
+        }
+        else if alpha > 0.0 {
+            character_image.color.set_alpha(alpha - time.delta_secs() * FADE_DELTA * 2.);
+        }
     }
+}
+
+// Starts typing out a line once it comes up, which is also when it's added to the backlog.
+pub fn dialog_box_line_starter(
+    mut commands: Commands,
+    mut text_query: Query<(Entity, &mut DialogTypewriter), With<DialogText>>,
+    npcs_query: Query<&NPC, With<NPC>>,
+    localization: Localization,
+    asset_server: Res<AssetServer>,
+    mut dialogue_backlog: ResMut<DialogueBacklog>,
+    mut name_plate: DialogNamePlateText,
+) {
+    let Some(current_conversation_info) = npcs_query.iter()
+        .find(|f| f.is_active)
+        .and_then(|npc| npc.conversation.get(npc.current_conversation_index)) else {
+        return;
+    };
 
-    let left_npc_alpha = left_npc_image.color.alpha();
+    for (entity, mut typewriter) in text_query.iter_mut() {
+        if typewriter.is_started() {
+            continue;
+        }
+
+        let conversation_text = localization.get_or(current_conversation_info.id.as_deref(), &current_conversation_info.text);
+        typewriter.start(&conversation_text, entity, &mut commands, &localization, &asset_server);
+
+        name_plate.show(&current_conversation_info.npc_name, &localization);
 
-    if left_npc_alpha > 0.0 {
-        left_npc_image.color.set_alpha(left_npc_alpha - time.delta_secs() * FADE_DELTA * 2.);
+        dialogue_backlog.push(BacklogEntry {
+            npc_name: current_conversation_info.npc_name.clone(),
+            emotion: current_conversation_info.emotion,
+            text: strip_markup(&conversation_text)
+        });
     }
 }
 
 pub fn dialog_box_text_writer(
-    mut text_query: Query<&mut Text, With<DialogText>>,
-    mut npcs_query: Query<&mut NPC, With<NPC>>,
+    mut commands: Commands,
+    mut text_query: Query<&mut DialogTypewriter, With<DialogText>>,
+    npcs_query: Query<&NPC, With<NPC>>,
     time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    dialogue_settings: Res<DialogueSettings>,
+    audio_settings: Res<AudioSettings>,
 ) {
-    if let Some(npc) = npcs_query.iter_mut().find(|f| f.is_active) {
-        let current_conversation_info = match npc.conversation.get(npc.current_conversation_index) {
-            Some(info) => info,
-            None => return,
-        };
-    
-        for mut text in text_query.iter_mut() {
-            let mut conversation_text = current_conversation_info.text.to_string();
+    let Some(current_conversation_info) = npcs_query.iter()
+        .find(|f| f.is_active)
+        .and_then(|npc| npc.conversation.get(npc.current_conversation_index)) else {
+        return;
+    };
 
-            let text_length = text.chars().count().max(1);
-            let chars_to_display = (time.delta_secs().ceil() as usize + text_length).min(conversation_text.chars().count());
+    let has_speaker = !current_conversation_info.npc_name.is_empty();
 
-            let _ = conversation_text.split_off(chars_to_display);
+    for mut typewriter in text_query.iter_mut() {
+        let typed_chars = typewriter.get_typed_chars();
+        typewriter.advance(time.delta_secs(), dialogue_settings.text_speed);
 
-            if conversation_text.chars().count() != text_length {
-                **text = conversation_text;
-            }
+        // Counting from the first character, so every line starts with a blip.
+        let blip_count = |chars: usize| chars.div_ceil(VOICE_BLIP_CHARS);
+
+        if dialogue_settings.voice_blips && has_speaker && blip_count(typewriter.get_typed_chars()) != blip_count(typed_chars) {
+            Voice::get(&current_conversation_info.npc_name).play(typewriter.get_typed_chars(), &mut commands, &asset_server, audio_settings.sfx_volume);
         }
     }
-}
\ No newline at end of file
+}
+
+pub fn dialog_box_span_updater(
+    text_query: Query<&DialogTypewriter, With<DialogText>>,
+    mut span_query: Query<&mut TextSpan>,
+) {
+    for typewriter in text_query.iter() {
+        typewriter.update_spans(&mut span_query);
+    }
+}
@@ -0,0 +1,129 @@
+use bevy::{audio::{PlaybackMode, Volume}, ecs::system::SystemParam, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::{audio_settings::AudioSettings, cutscene::{CutsceneEvent, CutsceneInfo, PostCutsceneAction}, level::{level_layout::BreakableWall, manually_transition_to_level, progression::Progression, transition_states::TransitionState, Level, LevelLayout}, save::SaveGameEvent, health::Health, Cweampuff};
+use crate::asset_loader::load_asset;
+
+use super::DialogueFlags;
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub enum Ability {
+    DoubleJump,
+    WallJump,
+    Dash
+}
+
+// Effects that happen once a conversation is over.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum ConversationAction {
+    // Progression never moves backwards, so replaying a conversation can't undo later progress.
+    SetProgression(Progression),
+    GrantAbility(Ability),
+    // Breaks the first breakable wall in the current level with the given index.
+    BreakWalls(u32),
+    StartCutscene {
+        slides: Vec<CutsceneInfo>,
+        bgm: String,
+        post_cutscene_action: PostCutsceneAction
+    },
+    TransitionTo {
+        level: Level,
+        position: Vec3
+    },
+    // Path to a sound effect, relative to the assets folder.
+    PlaySfx(String),
+    SetFlag(String)
+}
+
+#[derive(Event)]
+pub struct ConversationActionEvent(pub Vec<ConversationAction>);
+
+// The parts of the world outside the Cweampuff that conversation actions can change.
+#[derive(SystemParam)]
+pub struct ConversationActionWorld<'w, 's> {
+    breakable_walls: Query<'w, 's, (Entity, &'static BreakableWall)>,
+    current_level_layout: Query<'w, 's, Entity, With<LevelLayout>>,
+    transition_state: ResMut<'w, NextState<TransitionState>>,
+    cutscene_writer: EventWriter<'w, CutsceneEvent>,
+    asset_server: Res<'w, AssetServer>,
+    audio_settings: Res<'w, AudioSettings>,
+}
+
+impl ConversationActionWorld<'_, '_> {
+    fn break_wall(&self, index: u32, commands: &mut Commands) {
+        if let Some((entity, _)) = self.breakable_walls.iter().find(|(_, wall)| wall.index == index) {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    fn transition_to(&mut self, cweampuff: &Cweampuff, commands: &mut Commands, level: Level, position: Vec3) {
+        manually_transition_to_level(&self.current_level_layout, &mut self.transition_state, cweampuff, commands, level, position);
+    }
+
+    fn play_sfx(&self, path: &str, commands: &mut Commands) {
+        let mut playback_settings = PlaybackSettings::default().with_volume(Volume::Linear(self.audio_settings.sfx_volume));
+        playback_settings.mode = PlaybackMode::Despawn;
+
+        commands.spawn((
+            AudioPlayer::new(load_asset(&self.asset_server, path)),
+            playback_settings
+        ));
+    }
+}
+
+pub fn conversation_action_event_reader(
+    mut action_events: EventReader<ConversationActionEvent>,
+    mut cweampuff: Single<(&mut Cweampuff, &mut Health), With<Cweampuff>>,
+    mut commands: Commands,
+    mut save_writer: EventWriter<SaveGameEvent>,
+    mut dialogue_flags: ResMut<DialogueFlags>,
+    mut action_world: ConversationActionWorld,
+) {
+    let (cweampuff, cweampuff_health) = &mut *cweampuff;
+
+    for ConversationActionEvent(actions) in action_events.read() {
+        let mut should_save = false;
+
+        for action in actions {
+            match action {
+                ConversationAction::SetProgression(progression) => {
+                    if cweampuff.progression < *progression {
+                        cweampuff.progression = *progression;
+                        // Reaching a new part of the story is also a full heal.
+                        cweampuff_health.heal();
+                        should_save = true;
+                    }
+                },
+                ConversationAction::GrantAbility(ability) => {
+                    let has_ability = match ability {
+                        Ability::DoubleJump => &mut cweampuff.has_double_jump,
+                        Ability::WallJump => &mut cweampuff.has_wall_jump,
+                        Ability::Dash => &mut cweampuff.has_dash
+                    };
+
+                    should_save |= !*has_ability;
+                    *has_ability = true;
+                },
+                ConversationAction::BreakWalls(index) => {
+                    action_world.break_wall(*index, &mut commands);
+                },
+                ConversationAction::StartCutscene { slides, bgm, post_cutscene_action } => {
+                    action_world.cutscene_writer.write(CutsceneEvent::Started(slides.clone(), bgm.clone(), *post_cutscene_action));
+                },
+                ConversationAction::TransitionTo { level, position } => {
+                    action_world.transition_to(cweampuff, &mut commands, *level, *position);
+                },
+                ConversationAction::PlaySfx(path) => {
+                    action_world.play_sfx(path, &mut commands);
+                },
+                ConversationAction::SetFlag(flag) => {
+                    should_save |= dialogue_flags.0.insert(flag.clone());
+                }
+            }
+        }
+
+        if should_save {
+            save_writer.write(SaveGameEvent);
+        }
+    }
+}
@@ -1,12 +1,57 @@
 use std::fmt::Display;
 
-#[derive(Clone, Copy)]
+use bevy::log::warn;
+use serde::{Deserialize, Serialize};
+
+use super::conversation_action::ConversationAction;
+
+// Where the speaker of an entry stands. `Center` is for narration, it shows no portrait and the speaker's name is optional.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ConversationPosition {
     Left,
-    Right
+    MiddleLeft,
+    MiddleRight,
+    Right,
+    Center
 }
 
-#[derive(Clone, Copy, Default, Debug)]
+impl ConversationPosition {
+    // Every position with a portrait, each one gets its own image so participants don't have to share one.
+    pub const PORTRAIT_SLOTS: [ConversationPosition; 4] = [
+        ConversationPosition::Left,
+        ConversationPosition::MiddleLeft,
+        ConversationPosition::MiddleRight,
+        ConversationPosition::Right
+    ];
+
+    pub fn get_portrait_left(&self) -> f32 {
+        match self {
+            ConversationPosition::Left => 0.,
+            ConversationPosition::MiddleLeft => 17.5,
+            ConversationPosition::MiddleRight => 47.5,
+            ConversationPosition::Right => 65.,
+            ConversationPosition::Center => 32.5
+        }
+    }
+
+    // The dialog box sits next to the speaker's portrait.
+    pub fn get_text_box_left(&self) -> f32 {
+        match self {
+            ConversationPosition::Left => 35.,
+            ConversationPosition::MiddleLeft => 52.5,
+            ConversationPosition::MiddleRight => 7.5,
+            ConversationPosition::Right => 25.,
+            ConversationPosition::Center => 30.
+        }
+    }
+
+    // Portraits face the middle of the screen.
+    pub fn is_flipped(&self) -> bool {
+        matches!(self, ConversationPosition::Left | ConversationPosition::MiddleLeft)
+    }
+}
+
+#[derive(Clone, Copy, Default, Debug, Serialize, Deserialize)]
 pub enum Emotion {
     #[default]
     Regular,
@@ -15,16 +60,130 @@ pub enum Emotion {
     Surprised
 }
 
-#[derive(Clone)]
+// Entries play in order, unless `jump_to` names the label of another entry or `ends_conversation` is set.
+// An entry with choices waits until the player picks one of them.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ConversationEntry {
+    // Looks up the text in the current language's string table, the inline text is used when it has no translation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     pub position: ConversationPosition,
-    pub npc_name: &'static str,
-    pub text: &'static str,
-    pub emotion: Emotion
+    // Only narration can leave this out.
+    #[serde(default)]
+    pub npc_name: String,
+    pub text: String,
+    #[serde(default)]
+    pub emotion: Emotion,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<ConversationChoice>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jump_to: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub ends_conversation: bool
+}
+
+// Once a conversation has choices, the NPC's after conversation effects only happen if one of the picked choices triggers them.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ConversationChoice {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jump_to: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub triggers_after_conversation: bool,
+    // Runs once the conversation is over, on top of the NPC's own after conversation actions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub after_conversation: Vec<ConversationAction>
+}
+
+// Index of the entry that comes after `index`, which is past the end once the conversation is over.
+pub fn get_next_entry_index(conversation: &[ConversationEntry], index: usize) -> usize {
+    match conversation.get(index) {
+        Some(entry) if entry.ends_conversation => conversation.len(),
+        Some(ConversationEntry { jump_to: Some(label), .. }) => get_label_index(conversation, label),
+        _ => index + 1
+    }
+}
+
+pub fn get_label_index(conversation: &[ConversationEntry], label: &str) -> usize {
+    match conversation.iter().position(|entry| entry.label.as_deref() == Some(label)) {
+        Some(index) => index,
+        None => {
+            warn!("Conversation has no entry labelled {}", label);
+            conversation.len()
+        }
+    }
 }
 
 impl Display for Emotion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_entry(label: Option<&str>, jump_to: Option<&str>) -> ConversationEntry {
+        ConversationEntry {
+            id: None,
+            position: ConversationPosition::Left,
+            npc_name: "cweampuff".to_string(),
+            text: String::new(),
+            emotion: Emotion::Regular,
+            label: label.map(str::to_string),
+            choices: Vec::new(),
+            jump_to: jump_to.map(str::to_string),
+            ends_conversation: false
+        }
+    }
+
+    #[test]
+    fn entries_play_in_order_without_jumps() {
+        let conversation = [get_entry(None, None), get_entry(None, None)];
+
+        assert_eq!(get_next_entry_index(&conversation, 0), 1);
+        assert_eq!(get_next_entry_index(&conversation, 1), 2);
+    }
+
+    #[test]
+    fn jump_to_goes_to_the_labelled_entry() {
+        let conversation = [get_entry(None, Some("end")), get_entry(Some("skipped"), None), get_entry(Some("end"), None)];
+
+        assert_eq!(get_label_index(&conversation, "skipped"), 1);
+        assert_eq!(get_next_entry_index(&conversation, 0), 2);
+    }
+
+    #[test]
+    fn jump_to_can_go_backwards() {
+        let conversation = [get_entry(Some("start"), None), get_entry(None, Some("start"))];
+
+        assert_eq!(get_next_entry_index(&conversation, 1), 0);
+    }
+
+    #[test]
+    fn ends_conversation_wins_over_jump_to() {
+        let mut conversation = [get_entry(None, Some("next")), get_entry(Some("next"), None)];
+        conversation[0].ends_conversation = true;
+
+        assert_eq!(get_next_entry_index(&conversation, 0), conversation.len());
+    }
+
+    #[test]
+    fn missing_label_ends_the_conversation() {
+        let conversation = [get_entry(Some("start"), None)];
+
+        assert_eq!(get_label_index(&conversation, "missing"), conversation.len());
+    }
+
+    #[test]
+    fn jump_to_an_unknown_label_ends_the_conversation() {
+        let conversation = [get_entry(None, Some("unknown")), get_entry(None, None)];
+
+        assert_eq!(get_next_entry_index(&conversation, 0), conversation.len());
+    }
+}
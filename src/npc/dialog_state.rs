@@ -1,9 +1,10 @@
 use bevy::state::state::States;
 
+use super::conversation_entry::ConversationPosition;
+
 #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DialogState {
     #[default]
     None,
-    LeftCharacterTalking,
-    RightCharacterTalking,
+    CharacterTalking(ConversationPosition),
 }
\ No newline at end of file
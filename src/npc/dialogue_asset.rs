@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use bevy::{asset::{io::Reader, AssetLoader, LoadContext}, prelude::*};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::level::{level_asset::ProgressionRange, progression::Progression, Level};
+
+use super::{conversation_action::ConversationAction, conversation_entry::{ConversationEntry, ConversationPosition}, DialogueFlags, NPC_NAMES};
+
+#[derive(Asset, TypePath, Serialize, Deserialize)]
+pub struct DialogueAsset {
+    pub conversations: Vec<DialogueConversation>,
+}
+
+impl DialogueAsset {
+    pub fn get_conversation(&self, level: Level, progression: Progression, dialogue_flags: &DialogueFlags) -> Option<&DialogueConversation> {
+        self.conversations.iter()
+            .filter(|conversation| conversation.level == level && conversation.progression.contains(progression))
+            .find(|conversation| conversation.required_flags.iter().all(|flag| dialogue_flags.0.contains(flag)))
+    }
+
+    // Only narration can leave out who is speaking, every other position shows the speaker's portrait.
+    fn find_entry_without_speaker(&self) -> Option<&ConversationEntry> {
+        self.conversations.iter()
+            .flat_map(|conversation| conversation.entries.iter())
+            .find(|entry| entry.position != ConversationPosition::Center && entry.npc_name.is_empty())
+    }
+}
+
+// What an NPC says in a level while the Cweampuff's progression is within the given range.
+// The first matching conversation is used, so ones that require flags should come before the ones that don't.
+#[derive(Serialize, Deserialize)]
+pub struct DialogueConversation {
+    pub level: Level,
+    #[serde(default, skip_serializing_if = "ProgressionRange::is_always")]
+    pub progression: ProgressionRange,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_flags: Vec<String>,
+    pub entries: Vec<ConversationEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub after_conversation: Vec<ConversationAction>,
+}
+
+#[derive(Resource)]
+pub struct DialogueMap {
+    dialogues: HashMap<&'static str, Handle<DialogueAsset>>,
+}
+
+impl DialogueMap {
+    pub fn get_dialogue_handle(&self, npc_name: &str) -> Option<&Handle<DialogueAsset>> {
+        self.dialogues.get(npc_name)
+    }
+
+    pub fn get_dialogue_handles(&self) -> impl Iterator<Item = &Handle<DialogueAsset>> {
+        self.dialogues.values()
+    }
+}
+
+pub fn load_dialogues(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>
+) {
+    let dialogues = NPC_NAMES.iter()
+        .map(|name| (*name, asset_server.load(get_dialogue_path(name))))
+        .collect();
+
+    commands.insert_resource(DialogueMap { dialogues });
+}
+
+fn get_dialogue_path(npc_name: &str) -> String {
+    format!("dialogue/{}.dialogue.ron", npc_name.replace(' ', "_"))
+}
+
+#[derive(Default)]
+pub struct DialogueAssetLoader;
+
+#[derive(Debug, Error)]
+pub enum DialogueAssetLoaderError {
+    #[error("Could not read dialogue file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse dialogue file: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("Dialogue entry \"{0}\" is at {1:?} but has no npc_name, only Center entries can leave it out")]
+    MissingSpeaker(String, ConversationPosition),
+}
+
+impl AssetLoader for DialogueAssetLoader {
+    type Asset = DialogueAsset;
+    type Settings = ();
+    type Error = DialogueAssetLoaderError;
+
+    async fn load(&self, reader: &mut dyn Reader, _settings: &(), _load_context: &mut LoadContext<'_>) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let dialogue_asset = ron::de::from_bytes::<DialogueAsset>(&bytes)?;
+
+        if let Some(entry) = dialogue_asset.find_entry_without_speaker() {
+            return Err(DialogueAssetLoaderError::MissingSpeaker(entry.id.clone().unwrap_or_else(|| entry.text.clone()), entry.position));
+        }
+
+        Ok(dialogue_asset)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["dialogue.ron"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, fs};
+
+    use super::*;
+    use crate::localization::StringTable;
+
+    const DIALOGUE: &str = r#"(
+        conversations: [
+            (
+                level: CweamcatLair,
+                progression: (from: Some(MetMilk)),
+                required_flags: ["asked", "answered"],
+                entries: [(position: Right, npc_name: "milk", text: "Both flags")],
+            ),
+            (
+                level: CweamcatLair,
+                progression: (from: Some(MetMilk)),
+                required_flags: ["asked"],
+                entries: [(position: Right, npc_name: "milk", text: "One flag")],
+            ),
+            (
+                level: CweamcatLair,
+                progression: (from: Some(MetMilk)),
+                entries: [(position: Right, npc_name: "milk", text: "No flags")],
+            ),
+        ],
+    )"#;
+
+    fn get_text(dialogue_asset: &DialogueAsset, level: Level, progression: Progression, flags: &[&str]) -> Option<String> {
+        let dialogue_flags = DialogueFlags(flags.iter().map(|flag| flag.to_string()).collect::<BTreeSet<_>>());
+
+        dialogue_asset.get_conversation(level, progression, &dialogue_flags).map(|conversation| conversation.entries[0].text.clone())
+    }
+
+    #[test]
+    fn picks_the_first_conversation_whose_flags_are_all_set() {
+        let dialogue_asset: DialogueAsset = ron::from_str(DIALOGUE).unwrap();
+
+        assert_eq!(get_text(&dialogue_asset, Level::CweamcatLair, Progression::MetMilk, &[]).as_deref(), Some("No flags"));
+        assert_eq!(get_text(&dialogue_asset, Level::CweamcatLair, Progression::MetMilk, &["answered"]).as_deref(), Some("No flags"));
+        assert_eq!(get_text(&dialogue_asset, Level::CweamcatLair, Progression::MetMilk, &["asked"]).as_deref(), Some("One flag"));
+        assert_eq!(get_text(&dialogue_asset, Level::CweamcatLair, Progression::MetMilk, &["answered", "asked"]).as_deref(), Some("Both flags"));
+    }
+
+    #[test]
+    fn flags_do_not_unlock_conversations_of_other_levels_or_progressions() {
+        let dialogue_asset: DialogueAsset = ron::from_str(DIALOGUE).unwrap();
+
+        assert_eq!(get_text(&dialogue_asset, Level::CweamcatHouse, Progression::MetMilk, &["asked"]), None);
+        assert_eq!(get_text(&dialogue_asset, Level::CweamcatLair, Progression::None, &["asked"]), None);
+    }
+
+    #[test]
+    fn finds_entries_that_are_missing_a_speaker() {
+        let narration: DialogueAsset = ron::from_str(r#"(conversations: [(level: CweamcatLair, entries: [(position: Center, text: "Narration")])])"#).unwrap();
+        let missing_speaker: DialogueAsset = ron::from_str(r#"(conversations: [(level: CweamcatLair, entries: [(position: Left, text: "Who?")])])"#).unwrap();
+
+        assert!(narration.find_entry_without_speaker().is_none());
+        assert_eq!(missing_speaker.find_entry_without_speaker().map(|entry| entry.text.as_str()), Some("Who?"));
+    }
+
+    // Jumps to labels that don't exist would quietly cut conversations short in game.
+    #[test]
+    fn every_dialogue_file_parses_and_jumps_to_existing_labels() {
+        for npc_name in NPC_NAMES {
+            let path = format!("assets/{}", get_dialogue_path(npc_name));
+            let dialogue_asset: DialogueAsset = ron::from_str(&fs::read_to_string(&path).unwrap()).unwrap_or_else(|error| panic!("{}: {}", path, error));
+
+            assert!(dialogue_asset.find_entry_without_speaker().is_none(), "{} has an entry without a speaker", path);
+
+            for conversation in dialogue_asset.conversations.iter() {
+                let labels: Vec<&str> = conversation.entries.iter().filter_map(|entry| entry.label.as_deref()).collect();
+
+                let jumps = conversation.entries.iter()
+                    .flat_map(|entry| entry.jump_to.iter().chain(entry.choices.iter().flat_map(|choice| choice.jump_to.iter())));
+
+                for jump_to in jumps {
+                    assert!(labels.contains(&jump_to.as_str()), "{} jumps to unknown label {}", path, jump_to);
+                }
+            }
+        }
+    }
+
+    fn collect_ids(value: &ron::Value, ids: &mut Vec<String>) {
+        match value {
+            ron::Value::Map(map) => {
+                for (key, value) in map.iter() {
+                    match (key, value) {
+                        (ron::Value::String(key), ron::Value::Option(Some(id))) if key == "id" => {
+                            if let ron::Value::String(id) = id.as_ref() {
+                                ids.push(id.clone());
+                            }
+                        },
+                        (ron::Value::String(key), ron::Value::String(id)) if key == "id" => ids.push(id.clone()),
+                        _ => collect_ids(value, ids)
+                    }
+                }
+            },
+            ron::Value::Seq(values) => values.iter().for_each(|value| collect_ids(value, ids)),
+            ron::Value::Option(Some(value)) => collect_ids(value, ids),
+            _ => {}
+        }
+    }
+
+    // Lines without a translation silently fall back to English, so a missing id would only be noticed in game.
+    #[test]
+    fn every_dialogue_id_is_translated() {
+        let string_table: StringTable = ron::from_str(&fs::read_to_string("assets/localization/spanish.strings.ron").unwrap()).unwrap();
+        let mut ids = Vec::new();
+        let mut line_count = 0;
+
+        for npc_name in NPC_NAMES {
+            let path = format!("assets/{}", get_dialogue_path(npc_name));
+            let contents = fs::read_to_string(&path).unwrap();
+            let value: ron::Value = ron::from_str(&contents).unwrap_or_else(|error| panic!("{}: {}", path, error));
+            let dialogue_asset: DialogueAsset = ron::from_str(&contents).unwrap_or_else(|error| panic!("{}: {}", path, error));
+
+            collect_ids(&value, &mut ids);
+
+            line_count += dialogue_asset.conversations.iter()
+                .flat_map(|conversation| conversation.entries.iter())
+                .map(|entry| 1 + entry.choices.len())
+                .sum::<usize>();
+        }
+
+        // Every line and choice has an id, cutscene slides started from dialogue add their own on top.
+        assert!(!ids.is_empty());
+        assert!(ids.len() >= line_count, "only found {} ids for {} lines", ids.len(), line_count);
+
+        for id in ids {
+            assert!(string_table.strings.contains_key(&id), "{} has no Spanish translation", id);
+        }
+    }
+}
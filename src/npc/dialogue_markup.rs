@@ -0,0 +1,485 @@
+use bevy::{color::palettes::css, prelude::*, text::{ComputedTextBlock, TextLayoutInfo}};
+
+use crate::{dialogue_settings::TextSpeed, localization::{CustomFont, Localization}};
+
+pub const DIALOG_FONT_SIZE: f32 = 50.;
+pub const DIALOG_TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+// Shadows Into Light only comes in one face, so without a bold font in the string table bold text is drawn bigger instead.
+const BOLD_FONT_SCALE: f32 = 1.15;
+const SHAKE_AMPLITUDE: f32 = 2.5;
+// How many times per second shaking glyphs jump to a new spot.
+const SHAKE_RATE: f32 = 20.;
+const WAVE_AMPLITUDE: f32 = 5.;
+const WAVE_SPEED: f32 = 6.;
+// How far apart neighbouring glyphs are within the wave.
+const WAVE_SPACING: f32 = 0.5;
+
+#[derive(Component, Clone, Copy, PartialEq, Default)]
+pub enum TextEffect {
+    #[default]
+    None,
+    Shake,
+    Wave
+}
+
+#[derive(Clone, Default)]
+pub struct MarkupStyle {
+    pub color: Option<Color>,
+    pub bold: bool,
+    pub italic: bool,
+    pub effect: TextEffect
+}
+
+pub enum MarkupSegment {
+    Text(String, MarkupStyle),
+    Pause(f32)
+}
+
+// Dialogue lines can contain tags in curly braces:
+// {color=gold}...{/color}, {b}...{/b}, {i}...{/i}, {shake}...{/shake}, {wave}...{/wave} and {pause 0.5}.
+// Colors are either CSS color names or hex codes like #ffcc00. Tags that can't be parsed are shown as they are,
+// and {{ shows a curly brace without starting a tag.
+pub fn parse_markup(text: &str) -> Vec<MarkupSegment> {
+    let mut parser = MarkupParser::default();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('{') {
+        parser.text.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(escaped) = rest.strip_prefix("{{") {
+            parser.text.push('{');
+            rest = escaped;
+            continue;
+        }
+
+        match rest.find('}') {
+            Some(end) if parser.apply_tag(&rest[1..end]) => rest = &rest[end + 1..],
+            _ => {
+                warn!("Could not parse dialogue markup in \"{}\"", text);
+                parser.text.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    parser.text.push_str(rest);
+    parser.end_segment();
+
+    parser.segments
+}
+
+// The text of a dialogue line without any of its tags, for places that can't show styled text.
+pub fn strip_markup(text: &str) -> String {
+    parse_markup(text)
+        .into_iter()
+        .filter_map(|segment| match segment {
+            MarkupSegment::Text(text, _) => Some(text),
+            MarkupSegment::Pause(_) => None
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct MarkupParser {
+    segments: Vec<MarkupSegment>,
+    text: String,
+    style: MarkupStyle,
+    previous_colors: Vec<Option<Color>>
+}
+
+impl MarkupParser {
+    // Text written so far shares the current style, so it becomes a segment of its own before the style changes.
+    fn end_segment(&mut self) {
+        if !self.text.is_empty() {
+            self.segments.push(MarkupSegment::Text(std::mem::take(&mut self.text), self.style.clone()));
+        }
+    }
+
+    fn apply_tag(&mut self, tag: &str) -> bool {
+        let tag = tag.trim();
+
+        if let Some(seconds) = tag.strip_prefix("pause ") {
+            let Ok(seconds) = seconds.trim().parse::<f32>() else {
+                return false;
+            };
+
+            self.end_segment();
+            self.segments.push(MarkupSegment::Pause(seconds.max(0.)));
+
+            return true;
+        }
+
+        let mut style = self.style.clone();
+
+        if let Some(color) = tag.strip_prefix("color=") {
+            let Some(color) = parse_color(color.trim()) else {
+                return false;
+            };
+
+            self.previous_colors.push(style.color);
+            style.color = Some(color);
+        }
+        else {
+            match tag {
+                "/color" => style.color = self.previous_colors.pop().flatten(),
+                "b" => style.bold = true,
+                "/b" => style.bold = false,
+                "i" => style.italic = true,
+                "/i" => style.italic = false,
+                "shake" => style.effect = TextEffect::Shake,
+                "wave" => style.effect = TextEffect::Wave,
+                "/shake" | "/wave" => style.effect = TextEffect::None,
+                _ => return false
+            }
+        }
+
+        self.end_segment();
+        self.style = style;
+
+        true
+    }
+}
+
+fn parse_color(color: &str) -> Option<Color> {
+    if color.starts_with('#') {
+        return Srgba::hex(color).ok().map(Color::from);
+    }
+
+    let color = match color {
+        "white" => css::WHITE,
+        "gray" => css::GRAY,
+        "red" => css::RED,
+        "orange" => css::ORANGE,
+        "yellow" => css::YELLOW,
+        "gold" => css::GOLD,
+        "green" => css::LIME,
+        "blue" => css::DEEP_SKY_BLUE,
+        "purple" => css::MEDIUM_PURPLE,
+        "pink" => css::HOT_PINK,
+        _ => return None
+    };
+
+    Some(color.into())
+}
+
+// Reveals a dialogue line at the text speed from the settings, waiting wherever the line has a pause.
+// Each text segment of the line is shown by a text span under the dialog text.
+#[derive(Component, Default)]
+pub struct DialogTypewriter {
+    segments: Vec<MarkupSegment>,
+    spans: Vec<Entity>,
+    segment_index: usize,
+    revealed_chars: usize,
+    pause_timer: f32,
+    // Characters that were typed out, as opposed to shown all at once by completing the line.
+    typed_chars: usize,
+    // Time that passed since the last character showed up, which carries over so speed doesn't depend on the frame rate.
+    unspent_secs: f32,
+    is_started: bool
+}
+
+impl DialogTypewriter {
+    pub fn is_started(&self) -> bool {
+        self.is_started
+    }
+
+    pub fn get_typed_chars(&self) -> usize {
+        self.typed_chars
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.segment_index >= self.segments.len()
+    }
+
+    // The next line starts typing from the beginning, even if it has the same text as the last one.
+    pub fn reset(&mut self) {
+        self.is_started = false;
+    }
+
+    // Shows the rest of the line right away, skipping any pauses left in it.
+    pub fn complete(&mut self) {
+        self.segment_index = self.segments.len();
+        self.pause_timer = 0.;
+    }
+
+    pub fn start(&mut self, text: &str, dialog_text: Entity, commands: &mut Commands, localization: &Localization, asset_server: &AssetServer) {
+        for span in self.spans.drain(..) {
+            commands.entity(span).despawn();
+        }
+
+        self.segments = parse_markup(text);
+        self.segment_index = 0;
+        self.revealed_chars = 0;
+        self.pause_timer = 0.;
+        self.typed_chars = 0;
+        self.unspent_secs = 0.;
+        self.is_started = true;
+
+        for segment in self.segments.iter() {
+            let MarkupSegment::Text(_, style) = segment else {
+                continue;
+            };
+
+            let (font_path, font_size) = match (style.bold, style.italic) {
+                (true, _) => match localization.get_bold_font_path() {
+                    Some(path) => (path, DIALOG_FONT_SIZE),
+                    None => (localization.get_font_path(), DIALOG_FONT_SIZE * BOLD_FONT_SCALE)
+                },
+                (false, true) => (localization.get_italic_font_path().unwrap_or(localization.get_font_path()), DIALOG_FONT_SIZE),
+                (false, false) => (localization.get_font_path(), DIALOG_FONT_SIZE)
+            };
+
+            let mut span = commands.spawn((
+                TextSpan::default(),
+                TextFont {
+                    font: asset_server.load(font_path),
+                    font_size,
+                    ..default()
+                },
+                TextColor(style.color.unwrap_or(DIALOG_TEXT_COLOR)),
+                CustomFont,
+                ChildOf(dialog_text)
+            ));
+
+            if style.effect != TextEffect::None {
+                span.insert(style.effect);
+            }
+
+            self.spans.push(span.id());
+        }
+    }
+
+    pub fn advance(&mut self, delta_secs: f32, text_speed: TextSpeed) {
+        let secs_per_char = match text_speed {
+            TextSpeed::CharsPerSecond(chars_per_second) => chars_per_second.recip(),
+            TextSpeed::Instant => {
+                self.complete();
+                return;
+            }
+        };
+
+        self.unspent_secs += delta_secs;
+
+        loop {
+            if self.pause_timer > 0. {
+                if self.unspent_secs < self.pause_timer {
+                    self.pause_timer -= self.unspent_secs;
+                    self.unspent_secs = 0.;
+                    return;
+                }
+
+                self.unspent_secs -= self.pause_timer;
+                self.pause_timer = 0.;
+            }
+
+            match self.segments.get(self.segment_index) {
+                None => {
+                    self.unspent_secs = 0.;
+                    return;
+                },
+                Some(MarkupSegment::Pause(seconds)) => {
+                    self.pause_timer = *seconds;
+                    self.segment_index += 1;
+                },
+                Some(MarkupSegment::Text(text, _)) if self.revealed_chars < text.chars().count() => {
+                    if self.unspent_secs < secs_per_char {
+                        return;
+                    }
+
+                    self.unspent_secs -= secs_per_char;
+                    self.revealed_chars += 1;
+                    self.typed_chars += 1;
+                },
+                Some(MarkupSegment::Text(..)) => {
+                    self.segment_index += 1;
+                    self.revealed_chars = 0;
+                }
+            }
+        }
+    }
+
+    // How much of each text segment is shown so far, in the same order as the spans.
+    fn get_revealed_texts(&self) -> impl Iterator<Item = String> + '_ {
+        self.segments.iter()
+            .enumerate()
+            .filter_map(|(index, segment)| match segment {
+                MarkupSegment::Text(text, _) => Some(match index.cmp(&self.segment_index) {
+                    std::cmp::Ordering::Less => text.clone(),
+                    std::cmp::Ordering::Equal => text.chars().take(self.revealed_chars).collect(),
+                    std::cmp::Ordering::Greater => String::new()
+                }),
+                MarkupSegment::Pause(_) => None
+            })
+    }
+
+    pub fn update_spans(&self, span_query: &mut Query<&mut TextSpan>) {
+        for (revealed_text, span) in self.get_revealed_texts().zip(self.spans.iter()) {
+            let Ok(mut span) = span_query.get_mut(*span) else {
+                continue;
+            };
+
+            if **span != revealed_text {
+                **span = revealed_text;
+            }
+        }
+    }
+}
+
+// Where the glyphs of a text were laid out, before any text effect moved them.
+#[derive(Component, Default)]
+pub struct GlyphBasePositions(Vec<Vec2>);
+
+pub fn animate_text_effects(
+    mut text_query: Query<(&mut TextLayoutInfo, &ComputedTextBlock, &mut GlyphBasePositions)>,
+    effect_query: Query<&TextEffect>,
+    time: Res<Time>,
+) {
+    let elapsed = time.elapsed_secs();
+
+    for (mut text_layout_info, computed_block, mut base_positions) in text_query.iter_mut() {
+        // Moving the glyphs doesn't count as a change, so this only happens when the text was laid out again.
+        if text_layout_info.is_changed() {
+            base_positions.0 = text_layout_info.glyphs.iter().map(|glyph| glyph.position).collect();
+        }
+
+        let text_layout_info = text_layout_info.bypass_change_detection();
+
+        for (index, glyph) in text_layout_info.glyphs.iter_mut().enumerate() {
+            let Some(base_position) = base_positions.0.get(index) else {
+                continue;
+            };
+
+            let effect = computed_block.entities()
+                .get(glyph.span_index)
+                .and_then(|span| effect_query.get(span.entity).ok())
+                .copied()
+                .unwrap_or_default();
+
+            let offset = match effect {
+                TextEffect::None => Vec2::ZERO,
+                TextEffect::Shake => {
+                    let seed = (elapsed * SHAKE_RATE).floor() + index as f32 * 17.13;
+                    Vec2::new((seed * 12.9898).sin(), (seed * 78.233).sin()) * SHAKE_AMPLITUDE
+                },
+                TextEffect::Wave => Vec2::new(0., (elapsed * WAVE_SPEED - index as f32 * WAVE_SPACING).sin() * WAVE_AMPLITUDE)
+            };
+
+            glyph.position = *base_position + offset;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_texts(segments: &[MarkupSegment]) -> Vec<&str> {
+        segments.iter()
+            .filter_map(|segment| match segment {
+                MarkupSegment::Text(text, _) => Some(text.as_str()),
+                MarkupSegment::Pause(_) => None
+            })
+            .collect()
+    }
+
+    fn get_styles(segments: &[MarkupSegment]) -> Vec<&MarkupStyle> {
+        segments.iter()
+            .filter_map(|segment| match segment {
+                MarkupSegment::Text(_, style) => Some(style),
+                MarkupSegment::Pause(_) => None
+            })
+            .collect()
+    }
+
+    fn get_typewriter(text: &str) -> DialogTypewriter {
+        DialogTypewriter { segments: parse_markup(text), is_started: true, ..default() }
+    }
+
+    #[test]
+    fn nested_colors_go_back_to_the_outer_color() {
+        let segments = parse_markup("{color=gold}a{color=red}b{/color}c{/color}d");
+        let colors: Vec<Option<Color>> = get_styles(&segments).iter().map(|style| style.color).collect();
+
+        assert_eq!(get_texts(&segments), ["a", "b", "c", "d"]);
+        assert_eq!(colors, [Some(css::GOLD.into()), Some(css::RED.into()), Some(css::GOLD.into()), None]);
+    }
+
+    #[test]
+    fn nested_tags_keep_the_outer_style() {
+        let segments = parse_markup("{wave}a{b}b{i}c{/i}{/b}d{/wave}");
+        let styles = get_styles(&segments);
+
+        assert_eq!(get_texts(&segments), ["a", "b", "c", "d"]);
+        assert!(styles.iter().all(|style| style.effect == TextEffect::Wave));
+        assert_eq!(styles.iter().map(|style| (style.bold, style.italic)).collect::<Vec<_>>(), [(false, false), (true, false), (true, true), (false, false)]);
+    }
+
+    #[test]
+    fn unclosed_tags_last_until_the_end_of_the_line() {
+        let segments = parse_markup("a{shake}b{color=red}c");
+        let styles = get_styles(&segments);
+
+        assert_eq!(get_texts(&segments), ["a", "b", "c"]);
+        assert!(styles[2].effect == TextEffect::Shake);
+        assert_eq!(styles[2].color, Some(css::RED.into()));
+    }
+
+    #[test]
+    fn tags_that_cannot_be_parsed_are_shown_as_they_are() {
+        assert_eq!(strip_markup("a {shake b"), "a {shake b");
+        assert_eq!(strip_markup("a {unknown} b"), "a {unknown} b");
+        assert_eq!(strip_markup("{color=nope}a{pause soon}"), "{color=nope}a{pause soon}");
+        assert_eq!(strip_markup("a } b"), "a } b");
+    }
+
+    #[test]
+    fn double_braces_are_shown_as_a_brace() {
+        let segments = parse_markup("{{b}} {b}{{{/b}");
+
+        assert_eq!(get_texts(&segments), ["{b}} ", "{"]);
+        assert!(get_styles(&segments)[1].bold);
+    }
+
+    #[test]
+    fn typewriter_carries_on_across_effect_spans() {
+        let mut typewriter = get_typewriter("ab{wave}cd{/wave}{shake}e{/shake}");
+
+        typewriter.advance(0.25, TextSpeed::CharsPerSecond(10.));
+        assert_eq!(typewriter.get_revealed_texts().collect::<Vec<_>>(), ["ab", "", ""]);
+
+        typewriter.advance(0.1, TextSpeed::CharsPerSecond(10.));
+        assert_eq!(typewriter.get_revealed_texts().collect::<Vec<_>>(), ["ab", "c", ""]);
+
+        typewriter.advance(0.2, TextSpeed::CharsPerSecond(10.));
+        assert_eq!(typewriter.get_revealed_texts().collect::<Vec<_>>(), ["ab", "cd", "e"]);
+        assert!(typewriter.is_finished());
+        assert_eq!(typewriter.get_typed_chars(), 5);
+    }
+
+    #[test]
+    fn typewriter_waits_at_pauses() {
+        let mut typewriter = get_typewriter("a{pause 1}{wave}b{/wave}");
+
+        typewriter.advance(0.5, TextSpeed::CharsPerSecond(10.));
+        assert_eq!(typewriter.get_revealed_texts().collect::<Vec<_>>(), ["a", ""]);
+
+        typewriter.advance(0.55, TextSpeed::CharsPerSecond(10.));
+        assert_eq!(typewriter.get_revealed_texts().collect::<Vec<_>>(), ["a", ""]);
+
+        typewriter.advance(0.2, TextSpeed::CharsPerSecond(10.));
+        assert_eq!(typewriter.get_revealed_texts().collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn completing_shows_every_span_and_skips_pauses() {
+        let mut typewriter = get_typewriter("a{pause 5}{b}b{/b}{wave}c{/wave}");
+
+        typewriter.advance(0.1, TextSpeed::CharsPerSecond(10.));
+        typewriter.complete();
+
+        assert!(typewriter.is_finished());
+        assert_eq!(typewriter.get_revealed_texts().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(typewriter.get_typed_chars(), 1);
+    }
+}
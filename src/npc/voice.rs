@@ -0,0 +1,55 @@
+use bevy::{audio::{PlaybackMode, Volume}, prelude::*};
+
+use crate::asset_loader::load_asset;
+
+use super::{CLIPPER_DRONE, COOL_CWEAMPUFF, CREW_MEMBER, DRONE, GLORP, GRIM, MASKED_CWEAMPUFF, MILK, MILK_ASLEEP, MINAWAN, OBSERVER_CREW_MEMBER, OG_CREW_MEMBER, OG_CWEAMPUFF, OG_DRONE, OG_MINAWAN, RICH_CWEAMPUFF, SCIENTIST_MINAWAN};
+
+// A blip is played every time this many characters of a line are revealed.
+pub const VOICE_BLIP_CHARS: usize = 3;
+
+// The blip sound an NPC makes while talking, played at a random pitch within the range.
+pub struct Voice {
+    sound: &'static str,
+    min_pitch: f32,
+    max_pitch: f32
+}
+
+impl Voice {
+    const fn new(sound: &'static str, min_pitch: f32, max_pitch: f32) -> Self {
+        Voice { sound, min_pitch, max_pitch }
+    }
+
+    // Anyone without a voice of their own, including the Cweampuff, sounds like a Cweampuff.
+    pub fn get(npc_name: &str) -> Voice {
+        match npc_name {
+            MILK => Voice::new("sfx/voices/milk.wav", 0.95, 1.1),
+            MILK_ASLEEP => Voice::new("sfx/voices/milk.wav", 0.7, 0.8),
+            COOL_CWEAMPUFF | MASKED_CWEAMPUFF => Voice::new("sfx/voices/cweampuff.wav", 0.85, 1.0),
+            OG_CWEAMPUFF | RICH_CWEAMPUFF => Voice::new("sfx/voices/cweampuff.wav", 0.75, 0.9),
+            MINAWAN | SCIENTIST_MINAWAN => Voice::new("sfx/voices/minawan.wav", 0.95, 1.15),
+            OG_MINAWAN => Voice::new("sfx/voices/minawan.wav", 0.8, 0.95),
+            CREW_MEMBER | OBSERVER_CREW_MEMBER => Voice::new("sfx/voices/crew.wav", 0.95, 1.1),
+            OG_CREW_MEMBER => Voice::new("sfx/voices/crew.wav", 0.8, 0.95),
+            DRONE | CLIPPER_DRONE => Voice::new("sfx/voices/drone.wav", 0.95, 1.05),
+            OG_DRONE => Voice::new("sfx/voices/drone.wav", 0.85, 0.9),
+            GRIM => Voice::new("sfx/voices/grim.wav", 0.9, 1.0),
+            GLORP => Voice::new("sfx/voices/glorp.wav", 0.8, 1.2),
+            _ => Voice::new("sfx/voices/cweampuff.wav", 1.0, 1.2)
+        }
+    }
+
+    // The seed only needs to differ between blips, so the pitch doesn't sound the same every time.
+    pub fn play(&self, seed: usize, commands: &mut Commands, asset_server: &Res<AssetServer>, sfx_volume: f32) {
+        let random = ((seed as f32 * 12.9898).sin() * 43758.547).fract().abs();
+
+        let mut playback_settings = PlaybackSettings::default()
+            .with_volume(Volume::Linear(sfx_volume))
+            .with_speed(self.min_pitch + (self.max_pitch - self.min_pitch) * random);
+        playback_settings.mode = PlaybackMode::Despawn;
+
+        commands.spawn((
+            AudioPlayer::new(load_asset(asset_server, self.sound)),
+            playback_settings
+        ));
+    }
+}
@@ -0,0 +1,243 @@
+use std::{collections::BTreeSet, fs, io, path::{Path, PathBuf}, time::{SystemTime, UNIX_EPOCH}};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::{app_states::AppState, backlog_menu::DialogueBacklog, death::RespawnPoint, level::{manually_transition_to_level, transition_states::TransitionState, Level, LevelLayout}, npc::{DialogueFlags, ReadDialogue}, Cweampuff};
+
+// Bump this whenever SaveData changes shape, and handle the older versions in `read_save_data`.
+// Version 2 added `play_time` and `saved_at`, both of which default to 0 for version 1 saves.
+// Version 3 added `dialogue_flags`, which are empty for older saves.
+// Version 4 added `read_dialogue`, so older saves start with every line unread.
+// Version 5 added `checkpoint`, older saves haven't rested at one.
+pub const SAVE_VERSION: u32 = 5;
+pub const SAVE_SLOTS: u32 = 3;
+const GAME_DIRECTORY: &str = "Cweampuff's Adventure";
+const SAVES_DIRECTORY: &str = "saves";
+
+#[derive(Event)]
+pub struct SaveGameEvent;
+
+#[derive(Event)]
+pub struct LoadGameEvent(pub u32);
+
+#[derive(Resource, Default)]
+pub struct CurrentSaveSlot(pub u32);
+
+// Seconds spent in game on the current save slot.
+#[derive(Resource, Default)]
+pub struct PlayTime(pub f32);
+
+// Inserted while a save is being restored, so that spawn_cweampuff can pick up the stored state.
+#[derive(Resource)]
+pub struct LoadedSave(pub SaveData);
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SaveData {
+    pub version: u32,
+    pub cweampuff: Cweampuff,
+    pub level: Level,
+    pub position: Vec3,
+    #[serde(default)]
+    pub play_time: f32,
+    #[serde(default)]
+    pub saved_at: u64,
+    #[serde(default)]
+    pub dialogue_flags: BTreeSet<String>,
+    #[serde(default)]
+    pub read_dialogue: BTreeSet<String>,
+    // Level and position of the last checkpoint rested at.
+    #[serde(default)]
+    pub checkpoint: Option<(Level, Vec3)>
+}
+
+impl SaveData {
+    pub fn get_play_time_label(&self) -> String {
+        let total_seconds = self.play_time as u64;
+
+        format!("{:02}:{:02}:{:02}", total_seconds / 3600, (total_seconds / 60) % 60, total_seconds % 60)
+    }
+}
+
+pub fn get_user_data_directory() -> PathBuf {
+    let base_directory = if cfg!(target_os = "windows") {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    }
+    else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join("Library/Application Support"))
+    }
+    else {
+        std::env::var_os("XDG_DATA_HOME").map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+    };
+
+    base_directory.unwrap_or_else(|| PathBuf::from(".")).join(GAME_DIRECTORY)
+}
+
+// Writes to a temporary file first and then swaps it in, so that a crash mid-write doesn't corrupt the existing file.
+pub fn write_user_data_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+
+    fs::write(&temp_path, contents)?;
+    fs::rename(&temp_path, path)
+}
+
+fn get_save_path(slot: u32) -> PathBuf {
+    get_user_data_directory().join(SAVES_DIRECTORY).join(format!("slot_{}.ron", slot))
+}
+
+pub fn read_save_data(slot: u32) -> Option<SaveData> {
+    let path = get_save_path(slot);
+    let contents = fs::read_to_string(&path).ok()?;
+
+    let save_data = match ron::from_str::<SaveData>(&contents) {
+        Ok(save_data) => save_data,
+        Err(error) => {
+            warn!("Failed to parse save file {}: {}", path.display(), error);
+            return None;
+        }
+    };
+
+    if save_data.version > SAVE_VERSION {
+        warn!("Save file {} has unsupported version {}", path.display(), save_data.version);
+        return None;
+    }
+
+    Some(save_data)
+}
+
+pub fn get_most_recent_save_slot() -> Option<u32> {
+    (0..SAVE_SLOTS)
+        .filter_map(|slot| read_save_data(slot).map(|save_data| (slot, save_data.saved_at)))
+        .max_by_key(|(_, saved_at)| *saved_at)
+        .map(|(slot, _)| slot)
+}
+
+// New games go to the first empty slot. When every slot is taken, the player has to pick the save to overwrite.
+pub fn get_new_game_slot() -> Option<u32> {
+    (0..SAVE_SLOTS).find(|slot| read_save_data(*slot).is_none())
+}
+
+fn write_save_data(slot: u32, save_data: &SaveData) {
+    let path = get_save_path(slot);
+
+    let contents = match ron::ser::to_string_pretty(save_data, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => contents,
+        Err(error) => {
+            warn!("Failed to serialize save data: {}", error);
+            return;
+        }
+    };
+
+    if let Err(error) = write_user_data_file(&path, &contents) {
+        warn!("Failed to write save file {}: {}", path.display(), error);
+    }
+}
+
+pub fn autosave(mut save_writer: EventWriter<SaveGameEvent>) {
+    save_writer.write(SaveGameEvent);
+}
+
+// Progress that belongs to the current save slot, which new and loaded games both replace.
+#[derive(SystemParam)]
+pub struct SlotProgress<'w> {
+    current_slot: ResMut<'w, CurrentSaveSlot>,
+    play_time: ResMut<'w, PlayTime>,
+    dialogue_flags: ResMut<'w, DialogueFlags>,
+    read_dialogue: ResMut<'w, ReadDialogue>,
+    respawn_point: ResMut<'w, RespawnPoint>,
+    dialogue_backlog: ResMut<'w, DialogueBacklog>,
+}
+
+impl SlotProgress<'_> {
+    pub fn reset(&mut self, slot: u32) {
+        self.current_slot.0 = slot;
+        self.play_time.0 = 0.;
+        self.dialogue_flags.0.clear();
+        self.read_dialogue.0.clear();
+        *self.respawn_point = RespawnPoint::default();
+        self.dialogue_backlog.0.clear();
+    }
+
+    fn restore(&mut self, slot: u32, save_data: &SaveData) {
+        self.reset(slot);
+
+        self.play_time.0 = save_data.play_time;
+        self.dialogue_flags.0 = save_data.dialogue_flags.clone();
+        self.read_dialogue.0 = save_data.read_dialogue.clone();
+
+        if let Some((level, position)) = save_data.checkpoint {
+            *self.respawn_point = RespawnPoint { position, checkpoint_level: Some(level) };
+        }
+    }
+
+    fn get_save_data(&self, cweampuff: Cweampuff, level: Level, position: Vec3) -> SaveData {
+        SaveData {
+            version: SAVE_VERSION,
+            cweampuff,
+            level,
+            position,
+            play_time: self.play_time.0,
+            saved_at: SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or_default(),
+            dialogue_flags: self.dialogue_flags.0.clone(),
+            read_dialogue: self.read_dialogue.0.clone(),
+            checkpoint: self.respawn_point.checkpoint_level.map(|level| (level, self.respawn_point.position))
+        }
+    }
+}
+
+pub fn save_game_event_reader(
+    mut save_events: EventReader<SaveGameEvent>,
+    cweampuff: Query<(&Cweampuff, &Transform), With<Cweampuff>>,
+    level_layout_query: Query<&LevelLayout, With<LevelLayout>>,
+    slot_progress: SlotProgress,
+) {
+    if save_events.is_empty() {
+        return;
+    }
+
+    save_events.clear();
+
+    let Ok((cweampuff, transform)) = cweampuff.single() else {
+        return;
+    };
+
+    let Ok(level_layout) = level_layout_query.single() else {
+        return;
+    };
+
+    write_save_data(slot_progress.current_slot.0, &slot_progress.get_save_data(*cweampuff, level_layout.level, transform.translation));
+}
+
+pub fn load_game_event_reader(
+    mut load_events: EventReader<LoadGameEvent>,
+    mut commands: Commands,
+    mut app_state: ResMut<NextState<AppState>>,
+    mut transition_state: ResMut<NextState<TransitionState>>,
+    mut slot_progress: SlotProgress,
+    current_level_layout: Query<Entity, With<LevelLayout>>,
+) {
+    for LoadGameEvent(slot) in load_events.read() {
+        let Some(save_data) = read_save_data(*slot) else {
+            continue;
+        };
+
+        slot_progress.restore(*slot, &save_data);
+
+        commands.insert_resource(LoadedSave(save_data.clone()));
+        app_state.set(AppState::InGame);
+
+        manually_transition_to_level(&current_level_layout, &mut transition_state, &save_data.cweampuff, &mut commands, save_data.level, save_data.position);
+
+        return;
+    }
+}
+
+pub fn tick_play_time(mut play_time: ResMut<PlayTime>, time: Res<Time>) {
+    play_time.0 += time.delta_secs();
+}
@@ -0,0 +1,200 @@
+use std::{fs, path::PathBuf};
+
+use bevy::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{audio_settings::AudioSettings, controls::ControlBindings, dialogue_settings::DialogueSettings, localization::LocalizationSettings, save::{get_user_data_directory, write_user_data_file}};
+
+pub const SETTINGS_VERSION: u32 = 1;
+const SETTINGS_FILE: &str = "settings.ron";
+
+#[derive(Event)]
+pub struct SettingsChangedEvent;
+
+// Every section falls back to its default when missing, so new sections (video, input, accessibility...)
+// can be added here without invalidating settings files written by older versions of the game.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct SettingsFile {
+    version: u32,
+    audio: AudioSettings,
+    controls: ControlBindings,
+    localization: LocalizationSettings,
+    dialogue: DialogueSettings,
+}
+
+fn get_settings_path() -> PathBuf {
+    get_user_data_directory().join(SETTINGS_FILE)
+}
+
+fn read_settings_file() -> SettingsFile {
+    let path = get_settings_path();
+
+    let Ok(contents) = fs::read_to_string(&path) else {
+        return SettingsFile::default();
+    };
+
+    parse_settings_file(&contents).unwrap_or_else(|| {
+        warn!("Failed to parse settings file {}, falling back to defaults", path.display());
+        SettingsFile::default()
+    })
+}
+
+// Every section is parsed on its own, so a hand edited section that doesn't parse only loses its own settings.
+fn parse_settings_file(contents: &str) -> Option<SettingsFile> {
+    let sections = get_section_texts(contents)?;
+    let version = parse_section(&sections, "version");
+
+    if version > SETTINGS_VERSION {
+        warn!("Settings file has unsupported version {}, falling back to defaults", version);
+        return Some(SettingsFile::default());
+    }
+
+    Some(SettingsFile {
+        version,
+        audio: parse_section(&sections, "audio"),
+        controls: parse_section(&sections, "controls"),
+        localization: parse_section(&sections, "localization"),
+        dialogue: parse_section(&sections, "dialogue"),
+    })
+}
+
+fn parse_section<T: DeserializeOwned + Default>(sections: &[(&str, &str)], name: &str) -> T {
+    let Some((_, text)) = sections.iter().find(|(section_name, _)| *section_name == name) else {
+        return T::default();
+    };
+
+    ron::from_str(text).unwrap_or_else(|error| {
+        warn!("Failed to parse the {} settings, falling back to defaults: {}", name, error);
+        T::default()
+    })
+}
+
+// Splits the top level struct of the settings file into the name and text of each of its fields.
+fn get_section_texts(contents: &str) -> Option<Vec<(&str, &str)>> {
+    let contents = contents.trim().strip_suffix(')')?;
+    let body = &contents[contents.find('(')? + 1..];
+
+    let mut field_texts = Vec::new();
+    let mut field_start = 0;
+    let mut depth = 0;
+    let mut is_in_string = false;
+    let mut is_escaped = false;
+
+    for (index, character) in body.char_indices() {
+        if is_in_string {
+            match character {
+                _ if is_escaped => is_escaped = false,
+                '\\' => is_escaped = true,
+                '"' => is_in_string = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match character {
+            '"' => is_in_string = true,
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                field_texts.push(&body[field_start..index]);
+                field_start = index + 1;
+            },
+            _ => {}
+        }
+    }
+
+    field_texts.push(&body[field_start..]);
+
+    field_texts.into_iter()
+        .filter(|field_text| !field_text.trim().is_empty())
+        .map(|field_text| field_text.split_once(':').map(|(name, value)| (name.trim(), value.trim())))
+        .collect()
+}
+
+fn write_settings_file(settings_file: &SettingsFile) {
+    let path = get_settings_path();
+
+    let contents = match ron::ser::to_string_pretty(settings_file, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => contents,
+        Err(error) => {
+            warn!("Failed to serialize settings: {}", error);
+            return;
+        }
+    };
+
+    if let Err(error) = write_user_data_file(&path, &contents) {
+        warn!("Failed to write settings file {}: {}", path.display(), error);
+    }
+}
+
+pub fn load_settings(mut commands: Commands) {
+    let settings_file = read_settings_file();
+
+    commands.insert_resource(settings_file.audio.sanitized());
+    commands.insert_resource(settings_file.controls.sanitized());
+    commands.insert_resource(settings_file.localization);
+    commands.insert_resource(settings_file.dialogue.sanitized());
+}
+
+pub fn settings_changed_event_reader(
+    mut settings_events: EventReader<SettingsChangedEvent>,
+    audio_settings: Res<AudioSettings>,
+    control_bindings: Res<ControlBindings>,
+    localization_settings: Res<LocalizationSettings>,
+    dialogue_settings: Res<DialogueSettings>,
+) {
+    if settings_events.is_empty() {
+        return;
+    }
+
+    settings_events.clear();
+
+    write_settings_file(&SettingsFile {
+        version: SETTINGS_VERSION,
+        audio: audio_settings.clone(),
+        controls: control_bindings.clone(),
+        localization: localization_settings.clone(),
+        dialogue: dialogue_settings.clone(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{controls::GameAction, localization::Language};
+
+    use super::*;
+
+    #[test]
+    fn keeps_the_other_sections_when_one_does_not_parse() {
+        let settings_file = parse_settings_file("(
+            version: 1,
+            audio: (bgm_volume: 0.25, sfx_volume: 0.5),
+            controls: (bindings: {Jump: [NotAKey]}),
+            localization: (language: Spanish),
+        )").unwrap();
+
+        assert_eq!(settings_file.audio.bgm_volume, 0.25);
+        assert_eq!(settings_file.audio.sfx_volume, 0.5);
+        assert_eq!(settings_file.controls.get_keys(GameAction::Jump), ControlBindings::default().get_keys(GameAction::Jump));
+        assert_eq!(settings_file.localization.language, Language::Spanish);
+    }
+
+    #[test]
+    fn ignores_settings_from_newer_versions() {
+        let settings_file = parse_settings_file("(version: 99, audio: (bgm_volume: 0.25, sfx_volume: 0.5))").unwrap();
+
+        assert_eq!(settings_file.audio.bgm_volume, AudioSettings::default().bgm_volume);
+    }
+
+    #[test]
+    fn reads_back_what_it_writes() {
+        let mut settings_file = SettingsFile { version: SETTINGS_VERSION, ..default() };
+        settings_file.audio.sfx_volume = 0.75;
+
+        let contents = ron::ser::to_string_pretty(&settings_file, ron::ser::PrettyConfig::default()).unwrap();
+
+        assert_eq!(parse_settings_file(&contents).unwrap().audio.sfx_volume, 0.75);
+    }
+}